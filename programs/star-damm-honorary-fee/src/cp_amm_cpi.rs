@@ -0,0 +1,374 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::Instruction,
+    program::invoke_signed,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Account as SplTokenAccount;
+use crate::error::HonoraryFeeError;
+
+/// Meteora DAMM v2 (cp-amm) program ID
+pub const CP_AMM_PROGRAM_ID: Pubkey = pubkey!("cpamdpZCGKUy5JxQXB4dcpGPiikHawvSWAd6mEn1sGG");
+
+/// Pool authority PDA shared by all cp-amm pools
+pub const CP_AMM_POOL_AUTHORITY: Pubkey = pubkey!("HLnpSz9h2S4hiLQ43rnSD9XkcUThA7B8hQMKmDaiTLcC");
+
+/// Seed of the Anchor event authority PDA used by cp-amm's emit_cpi
+pub const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+
+/// Anchor instruction discriminators (sha256("global:<name>")[..8])
+pub const CREATE_POSITION_DISCRIMINATOR: [u8; 8] = [48, 215, 197, 153, 96, 203, 180, 133];
+pub const CLAIM_POSITION_FEE_DISCRIMINATOR: [u8; 8] = [180, 38, 154, 17, 133, 33, 162, 211];
+
+/// Anchor account discriminator of the cp-amm `Position` account (sha256("account:Position")[..8])
+pub const POSITION_ACCOUNT_DISCRIMINATOR: [u8; 8] = [170, 188, 143, 228, 122, 64, 247, 208];
+
+/// Byte offsets of the cp-amm `Position` fields, including the 8-byte discriminator
+pub mod position_offsets {
+    pub const POOL: usize = 8;
+    pub const NFT_MINT: usize = 40;
+    pub const FEE_A_PER_TOKEN_CHECKPOINT: usize = 72;
+    pub const FEE_B_PER_TOKEN_CHECKPOINT: usize = 104;
+    pub const FEE_A_PENDING: usize = 136;
+    pub const FEE_B_PENDING: usize = 144;
+    pub const UNLOCKED_LIQUIDITY: usize = 152;
+    pub const VESTED_LIQUIDITY: usize = 168;
+    pub const PERMANENT_LOCKED_LIQUIDITY: usize = 184;
+    pub const METRICS_TOTAL_CLAIMED_A_FEE: usize = 200;
+    pub const METRICS_TOTAL_CLAIMED_B_FEE: usize = 208;
+
+    /// End of the last field read by this module
+    pub const PARSED_END: usize = 216;
+}
+
+/// Fee-owed fields of a cp-amm `Position` account
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PositionFees {
+    pub pool: Pubkey,
+    pub nft_mint: Pubkey,
+    /// Token A fees owed to the position as of its last update
+    pub fee_a_pending: u64,
+    /// Token B fees owed to the position as of its last update
+    pub fee_b_pending: u64,
+    /// Token A fees transferred out of the position over its lifetime
+    pub total_claimed_a_fee: u64,
+    /// Token B fees transferred out of the position over its lifetime
+    pub total_claimed_b_fee: u64,
+}
+
+impl PositionFees {
+    /// Parse the fee-owed fields from raw `Position` account data
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= position_offsets::PARSED_END
+                && data[..8] == POSITION_ACCOUNT_DISCRIMINATOR,
+            HonoraryFeeError::InvalidPositionOwner
+        );
+
+        let read_pubkey = |offset: usize| Pubkey::try_from(&data[offset..offset + 32]).unwrap();
+        let read_u64 = |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+
+        Ok(Self {
+            pool: read_pubkey(position_offsets::POOL),
+            nft_mint: read_pubkey(position_offsets::NFT_MINT),
+            fee_a_pending: read_u64(position_offsets::FEE_A_PENDING),
+            fee_b_pending: read_u64(position_offsets::FEE_B_PENDING),
+            total_claimed_a_fee: read_u64(position_offsets::METRICS_TOTAL_CLAIMED_A_FEE),
+            total_claimed_b_fee: read_u64(position_offsets::METRICS_TOTAL_CLAIMED_B_FEE),
+        })
+    }
+
+    /// Fees owed as `(quote, base)`, oriented by the pool's token mints
+    ///
+    /// Returns `None` when neither pool mint is the quote mint.
+    pub fn quote_and_base_pending(
+        &self,
+        token_a_mint: &Pubkey,
+        token_b_mint: &Pubkey,
+        quote_mint: &Pubkey,
+    ) -> Option<(u64, u64)> {
+        orient(self.fee_a_pending, self.fee_b_pending, token_a_mint, token_b_mint, quote_mint)
+    }
+
+    /// Fees claimed between `earlier` and this read as `(quote, base)`, oriented like
+    /// `quote_and_base_pending`
+    ///
+    /// Taken from the position's lifetime claim metrics, which only `claim_position_fee` moves,
+    /// so tokens sent to the receiving accounts by anyone else are not counted.
+    pub fn quote_and_base_claimed_since(
+        &self,
+        earlier: &Self,
+        token_a_mint: &Pubkey,
+        token_b_mint: &Pubkey,
+        quote_mint: &Pubkey,
+    ) -> Option<(u64, u64)> {
+        let claimed_a = self.total_claimed_a_fee.checked_sub(earlier.total_claimed_a_fee)?;
+        let claimed_b = self.total_claimed_b_fee.checked_sub(earlier.total_claimed_b_fee)?;
+        orient(claimed_a, claimed_b, token_a_mint, token_b_mint, quote_mint)
+    }
+}
+
+/// Require a claim's receiving account to have grown by at least the claimed amount
+///
+/// Growth beyond it (tokens sent by anyone else) is tolerated as surplus.
+pub fn require_claim_received(balance_before: u64, balance_after: u64, claimed: u64) -> Result<()> {
+    if balance_after.saturating_sub(balance_before) < claimed {
+        msg!("Claim of {} moved the receiving account from {} to {}", claimed, balance_before, balance_after);
+        return err!(HonoraryFeeError::TreasuryDeltaMismatch);
+    }
+    Ok(())
+}
+
+/// Require the position NFT, and with it the position's authority, to be held by the PDA
+///
+/// The position must belong to `pool`, and the NFT account must not have a delegate that
+/// could move the NFT, and with it the authority, away from the PDA.
+pub fn verify_position_authority(
+    position: &PositionFees,
+    position_nft_account: &SplTokenAccount,
+    position_owner_pda: &Pubkey,
+    pool: &Pubkey,
+) -> Result<()> {
+    require!(
+        position.pool == *pool
+            && position_nft_account.mint == position.nft_mint
+            && position_nft_account.owner == *position_owner_pda
+            && position_nft_account.amount == 1
+            && position_nft_account.delegate.is_none(),
+        HonoraryFeeError::InvalidPositionOwner
+    );
+    Ok(())
+}
+
+/// Order a `(token_a, token_b)` pair as `(quote, base)`; `None` when neither mint is the quote mint
+fn orient(
+    amount_a: u64,
+    amount_b: u64,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    quote_mint: &Pubkey,
+) -> Option<(u64, u64)> {
+    if token_a_mint == quote_mint {
+        Some((amount_a, amount_b))
+    } else if token_b_mint == quote_mint {
+        Some((amount_b, amount_a))
+    } else {
+        None
+    }
+}
+
+/// Derive the cp-amm event authority PDA
+pub fn get_event_authority_pda(cp_amm_program: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EVENT_AUTHORITY_SEED], cp_amm_program)
+}
+
+/// Account keys for `create_position`, in cp-amm IDL order
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreatePositionKeys {
+    pub owner: Pubkey,
+    pub position_nft_mint: Pubkey,
+    pub position_nft_account: Pubkey,
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub pool_authority: Pubkey,
+    pub payer: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+    pub event_authority: Pubkey,
+    pub program: Pubkey,
+}
+
+impl CreatePositionKeys {
+    pub fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.owner, false),
+            AccountMeta::new(self.position_nft_mint, true),
+            AccountMeta::new(self.position_nft_account, false),
+            AccountMeta::new(self.pool, false),
+            AccountMeta::new(self.position, false),
+            AccountMeta::new_readonly(self.pool_authority, false),
+            AccountMeta::new(self.payer, true),
+            AccountMeta::new_readonly(self.token_program, false),
+            AccountMeta::new_readonly(self.system_program, false),
+            AccountMeta::new_readonly(self.event_authority, false),
+            AccountMeta::new_readonly(self.program, false),
+        ]
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        Instruction {
+            program_id: self.program,
+            accounts: self.to_account_metas(),
+            data: CREATE_POSITION_DISCRIMINATOR.to_vec(),
+        }
+    }
+}
+
+/// Account keys for `claim_position_fee`, in cp-amm IDL order
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClaimPositionFeeKeys {
+    pub pool_authority: Pubkey,
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub token_a_account: Pubkey,
+    pub token_b_account: Pubkey,
+    pub token_a_vault: Pubkey,
+    pub token_b_vault: Pubkey,
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub position_nft_account: Pubkey,
+    pub owner: Pubkey,
+    pub token_a_program: Pubkey,
+    pub token_b_program: Pubkey,
+    pub event_authority: Pubkey,
+    pub program: Pubkey,
+}
+
+impl ClaimPositionFeeKeys {
+    pub fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.pool_authority, false),
+            AccountMeta::new_readonly(self.pool, false),
+            AccountMeta::new(self.position, false),
+            AccountMeta::new(self.token_a_account, false),
+            AccountMeta::new(self.token_b_account, false),
+            AccountMeta::new(self.token_a_vault, false),
+            AccountMeta::new(self.token_b_vault, false),
+            AccountMeta::new_readonly(self.token_a_mint, false),
+            AccountMeta::new_readonly(self.token_b_mint, false),
+            AccountMeta::new_readonly(self.position_nft_account, false),
+            AccountMeta::new_readonly(self.owner, true),
+            AccountMeta::new_readonly(self.token_a_program, false),
+            AccountMeta::new_readonly(self.token_b_program, false),
+            AccountMeta::new_readonly(self.event_authority, false),
+            AccountMeta::new_readonly(self.program, false),
+        ]
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        Instruction {
+            program_id: self.program,
+            accounts: self.to_account_metas(),
+            data: CLAIM_POSITION_FEE_DISCRIMINATOR.to_vec(),
+        }
+    }
+}
+
+/// Typed accounts for the `create_position` CPI
+pub struct CreatePosition<'info> {
+    pub owner: AccountInfo<'info>,
+    pub position_nft_mint: AccountInfo<'info>,
+    pub position_nft_account: AccountInfo<'info>,
+    pub pool: AccountInfo<'info>,
+    pub position: AccountInfo<'info>,
+    pub pool_authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub event_authority: AccountInfo<'info>,
+    pub program: AccountInfo<'info>,
+}
+
+impl<'info> CreatePosition<'info> {
+    pub fn keys(&self) -> CreatePositionKeys {
+        CreatePositionKeys {
+            owner: self.owner.key(),
+            position_nft_mint: self.position_nft_mint.key(),
+            position_nft_account: self.position_nft_account.key(),
+            pool: self.pool.key(),
+            position: self.position.key(),
+            pool_authority: self.pool_authority.key(),
+            payer: self.payer.key(),
+            token_program: self.token_program.key(),
+            system_program: self.system_program.key(),
+            event_authority: self.event_authority.key(),
+            program: self.program.key(),
+        }
+    }
+
+    pub fn invoke_signed(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        invoke_signed(
+            &self.keys().instruction(),
+            &[
+                self.owner.clone(),
+                self.position_nft_mint.clone(),
+                self.position_nft_account.clone(),
+                self.pool.clone(),
+                self.position.clone(),
+                self.pool_authority.clone(),
+                self.payer.clone(),
+                self.token_program.clone(),
+                self.system_program.clone(),
+                self.event_authority.clone(),
+                self.program.clone(),
+            ],
+            signer_seeds,
+        )
+        .map_err(Into::into)
+    }
+}
+
+/// Typed accounts for the `claim_position_fee` CPI
+pub struct ClaimPositionFee<'info> {
+    pub pool_authority: AccountInfo<'info>,
+    pub pool: AccountInfo<'info>,
+    pub position: AccountInfo<'info>,
+    pub token_a_account: AccountInfo<'info>,
+    pub token_b_account: AccountInfo<'info>,
+    pub token_a_vault: AccountInfo<'info>,
+    pub token_b_vault: AccountInfo<'info>,
+    pub token_a_mint: AccountInfo<'info>,
+    pub token_b_mint: AccountInfo<'info>,
+    pub position_nft_account: AccountInfo<'info>,
+    pub owner: AccountInfo<'info>,
+    pub token_a_program: AccountInfo<'info>,
+    pub token_b_program: AccountInfo<'info>,
+    pub event_authority: AccountInfo<'info>,
+    pub program: AccountInfo<'info>,
+}
+
+impl<'info> ClaimPositionFee<'info> {
+    pub fn keys(&self) -> ClaimPositionFeeKeys {
+        ClaimPositionFeeKeys {
+            pool_authority: self.pool_authority.key(),
+            pool: self.pool.key(),
+            position: self.position.key(),
+            token_a_account: self.token_a_account.key(),
+            token_b_account: self.token_b_account.key(),
+            token_a_vault: self.token_a_vault.key(),
+            token_b_vault: self.token_b_vault.key(),
+            token_a_mint: self.token_a_mint.key(),
+            token_b_mint: self.token_b_mint.key(),
+            position_nft_account: self.position_nft_account.key(),
+            owner: self.owner.key(),
+            token_a_program: self.token_a_program.key(),
+            token_b_program: self.token_b_program.key(),
+            event_authority: self.event_authority.key(),
+            program: self.program.key(),
+        }
+    }
+
+    pub fn invoke_signed(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        invoke_signed(
+            &self.keys().instruction(),
+            &[
+                self.pool_authority.clone(),
+                self.pool.clone(),
+                self.position.clone(),
+                self.token_a_account.clone(),
+                self.token_b_account.clone(),
+                self.token_a_vault.clone(),
+                self.token_b_vault.clone(),
+                self.token_a_mint.clone(),
+                self.token_b_mint.clone(),
+                self.position_nft_account.clone(),
+                self.owner.clone(),
+                self.token_a_program.clone(),
+                self.token_b_program.clone(),
+                self.event_authority.clone(),
+                self.program.clone(),
+            ],
+            signer_seeds,
+        )
+        .map_err(Into::into)
+    }
+}
@@ -38,7 +38,7 @@ pub enum HonoraryFeeError {
     #[msg("Position not owned by program PDA")]
     InvalidPositionOwner,
     
-    #[msg("Base token fees detected during claim - aborting distribution")]
+    #[msg("Base token fees detected during claim")]
     BaseFeesInClaim,
     
     #[msg("Invalid quote mint for this vault")]
@@ -46,4 +46,253 @@ pub enum HonoraryFeeError {
     
     #[msg("Treasury ATA not found or invalid")]
     InvalidTreasury,
-}
\ No newline at end of file
+    
+    #[msg("Quote mint and associated token program are required to create investor ATAs")]
+    MissingAtaCreationAccounts,
+    
+    #[msg("Unsupported Streamflow contract version")]
+    UnsupportedStreamVersion,
+    
+    #[msg("Operation not allowed while a distribution day is in progress")]
+    DayInProgress,
+    
+    #[msg("No dust to sweep")]
+    NoDustToSweep,
+    
+    #[msg("Signer is not authorized for this action")]
+    Unauthorized,
+    
+    #[msg("Vault has been sunset")]
+    VaultSunset,
+    
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+    
+    #[msg("Creator must acknowledge creator-only payouts before the day can close")]
+    CreatorAcknowledgmentRequired,
+    
+    #[msg("No creator-only streak to acknowledge")]
+    NoCreatorOnlyStreak,
+    
+    #[msg("Creator vesting period cannot be negative")]
+    InvalidVestingPeriod,
+    
+    #[msg("No vested creator fees to claim")]
+    NothingToClaim,
+    
+    #[msg("New quote mint must differ from the current quote mint")]
+    QuoteMintUnchanged,
+    
+    #[msg("Treasury still holds unsettled dust, donations, carry-over, creator escrow or unclaimed allocations")]
+    UnsettledTreasury,
+    
+    #[msg("Page size is outside the policy bounds")]
+    PageSizeOutOfBounds,
+    
+    #[msg("Invalid page size bounds")]
+    InvalidPageSizeBounds,
+    
+    #[msg("Result does not fit in return data; use a smaller page")]
+    ReturnDataTooLarge,
+    
+    #[msg("Investor registry is full")]
+    RegistryFull,
+    
+    #[msg("Investor is already registered")]
+    InvestorAlreadyRegistered,
+    
+    #[msg("Investor registry and locked snapshot accounts are required for this vault")]
+    RegistryAccountsRequired,
+    
+    #[msg("Locked snapshot must cover every registered investor since the last day started")]
+    SnapshotIncomplete,
+    
+    #[msg("Snapshot entries must be recorded in registry order")]
+    SnapshotOutOfOrder,
+    
+    #[msg("Account does not match the registered investor at this index")]
+    InvestorNotRegistered,
+    
+    #[msg("Investor set commitment is required at day start in Merkle mode and only then")]
+    InvalidInvestorSetCommitment,
+    
+    #[msg("Merkle proof does not match the committed investor set")]
+    InvalidMerkleProof,
+    
+    #[msg("Registry, Merkle investor set and pull-claim modes cannot be combined")]
+    InvestorSetModeConflict,
+    
+    #[msg("Instruction is not available in this vault's distribution mode")]
+    InvalidDistributionMode,
+    
+    #[msg("Pull-claim day is waiting for its claim root")]
+    ClaimRootPending,
+    
+    #[msg("Allocated amount exceeds the investor share available today")]
+    ClaimAllocationExceedsCap,
+    
+    #[msg("Too many claim leaves")]
+    TooManyClaimLeaves,
+    
+    #[msg("Allocation already claimed")]
+    AlreadyClaimed,
+    
+    #[msg("Payout record does not belong to this vault and investor")]
+    InvalidPayoutRecord,
+    
+    #[msg("Investor index exceeds the per-day processing limit")]
+    TooManyInvestors,
+    
+    #[msg("Pool does not match the vault's pool")]
+    PoolMismatch,
+    
+    #[msg("Investor count does not match the count committed at day start")]
+    InvestorCountMismatch,
+    
+    #[msg("Paired quote mint treasury and progress accounts are required")]
+    MissingPairedQuoteAccounts,
+    
+    #[msg("Investor weight multiplier is out of bounds")]
+    InvalidMultiplier,
+    
+    #[msg("Invalid fee-share schedule")]
+    InvalidFeeShareSchedule,
+    
+    #[msg("Day anchor offset must be within a day")]
+    InvalidDayAnchorOffset,
+    
+    #[msg("Max investors must be between 1 and MAX_INVESTORS_PER_DAY")]
+    InvalidMaxInvestors,
+    
+    #[msg("Invalid payout receipt account")]
+    InvalidPayoutReceipt,
+    
+    #[msg("Treasury balance does not match the recorded distributions")]
+    InvariantViolation,
+    
+    #[msg("Distributions are paused")]
+    VaultPaused,
+    
+    #[msg("Distributions are not paused")]
+    VaultNotPaused,
+    
+    #[msg("Global config allow-lists or parameter bounds are invalid")]
+    InvalidGlobalConfig,
+    
+    #[msg("Program is not in the global config allow-list")]
+    ProgramNotAllowed,
+    
+    #[msg("Policy parameter exceeds the global config bounds")]
+    ParameterOutOfBounds,
+    
+    #[msg("Minimum payout basis points must be at most 10000")]
+    InvalidMinPayoutBps,
+    
+    #[msg("Quote mint decimals exceed the supported maximum")]
+    InvalidQuoteDecimals,
+    
+    #[msg("Daily cap does not fit the required number of minimum payouts")]
+    MinPayoutExceedsCap,
+    
+    #[msg("Policy account is not a valid policy state")]
+    InvalidPolicyAccount,
+    
+    #[msg("Crank submitted before the minimum crank interval elapsed")]
+    CrankTooSoon,
+    
+    #[msg("Investor quote ATA is not a valid token account of the quote mint and stream recipient")]
+    InvalidInvestorAta,
+    
+    #[msg("Investor quote ATA does not exist")]
+    MissingInvestorAta,
+    
+    #[msg("Stream account without a paired investor ATA")]
+    MissingAccountPair,
+    
+    #[msg("Stream mint does not match the vault's allocation mint")]
+    StreamMintMismatch,
+    
+    #[msg("Stream appears more than once in the page")]
+    DuplicateInvestor,
+    
+    #[msg("Page size must be greater than zero")]
+    ZeroPageSize,
+    
+    #[msg("Page does not list exactly the registry entries from its start index")]
+    NonCanonicalPage,
+    
+    #[msg("Treasury balance did not move by exactly the tracked outflow")]
+    TreasuryDeltaMismatch,
+    
+    #[msg("Pool is disabled")]
+    PoolDisabled,
+    
+    #[msg("Treasury has a delegate or close authority")]
+    TreasuryEncumbered,
+    
+    #[msg("A day start is already in progress")]
+    DayStartInProgress,
+    
+    #[msg("Program account does not match the program recorded in the policy")]
+    ProgramIdMismatch,
+    
+    #[msg("Investor fee share must be at most 10000 basis points")]
+    InvalidInvestorFeeShare,
+    
+    #[msg("Total investor allocation must be greater than zero")]
+    ZeroInvestorAllocation,
+    
+    #[msg("Investor quote ATA is not writable")]
+    InvestorAtaNotWritable,
+    
+    #[msg("Investor quote ATA is not owned by the token program")]
+    InvestorAtaNotTokenAccount,
+    
+    #[msg("Single-quote day starts require the base treasury")]
+    MissingBaseTreasury,
+    
+    #[msg("Stream sender must be set")]
+    InvalidStreamSender,
+    
+    #[msg("Stream was not created by the vault's stream sender")]
+    ForeignStream,
+    
+    #[msg("Fees were already claimed for the current day")]
+    DayAlreadyClaimed,
+    
+    #[msg("Day cannot close before every registered investor is processed")]
+    RegistryIncomplete,
+    
+    #[msg("Pool account is not a cp-amm Pool")]
+    InvalidPoolAccount,
+    
+    #[msg("Stream account amounts or timestamps are inconsistent")]
+    InconsistentStreamAccount,
+    
+    #[msg("Treasury holds less than the current day still owes")]
+    TreasuryMismatch,
+    
+    #[msg("Page size exceeds what a page can process within the compute budget")]
+    PageTooLarge,
+    
+    #[msg("Day close does not account for every token of the day")]
+    ConservationViolation,
+    
+    #[msg("Investor quote ATA is frozen")]
+    InvestorAtaFrozen,
+    
+    #[msg("Clock is earlier than the last recorded distribution")]
+    ClockRegression,
+    
+    #[msg("Anchored days require the default 24-hour distribution period")]
+    InvalidDistributionPeriod,
+    
+    #[msg("Day start is missing the cp-amm claim accounts")]
+    MissingClaimAccounts,
+    
+    #[msg("Quote and base mints must be the pool's two mints")]
+    PoolMintMismatch,
+    
+    #[msg("Quote and base mints must differ")]
+    QuoteMintIsBaseMint,
+}
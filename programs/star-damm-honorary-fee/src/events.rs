@@ -1,12 +1,22 @@
 use anchor_lang::prelude::*;
+use crate::{
+    error::HonoraryFeeError,
+    state::{DustDestination, MinPayoutMode, PauseReason, ResidueDestination},
+};
 
 /// Event emitted when honorary position is initialized
 #[event]
 pub struct HonoraryPositionInitialized {
     pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
     pub position_owner_pda: Pubkey,
     pub quote_mint: Pubkey,
     pub position: Pubkey,
+    /// Position of the vault in the program-wide vault index
+    pub vault_index: u64,
     pub timestamp: i64,
 }
 
@@ -14,8 +24,119 @@ pub struct HonoraryPositionInitialized {
 #[event]
 pub struct QuoteFeesClaimed {
     pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
     pub amount_claimed: u64,
+    /// Base-token fees returned by the same claim (the claim aborts when nonzero)
+    pub base_amount_claimed: u64,
     pub quote_mint: Pubkey,
+    /// Decimals of `quote_mint`, for rendering the amounts
+    pub quote_decimals: u8,
+    pub timestamp: i64,
+}
+
+/// Event emitted when the first page of a day fixes the day's distribution parameters
+#[event]
+pub struct DayStarted {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    /// Quote fees claimed for the day
+    pub claimed_amount: u64,
+    /// Undistributed amount carried over from earlier days
+    pub carry_over: u64,
+    /// Locked total every page of the day divides by
+    pub total_locked: u64,
+    pub eligible_share_bps: u16,
+    pub expected_investor_count: u64,
+    pub timestamp: i64,
+}
+
+/// Event emitted when the honorary position returned base-token fees
+///
+/// Logged after the claim, alongside the pause that quarantines the fees in the base treasury.
+#[event]
+pub struct BaseFeesDetected {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub base_mint: Pubkey,
+    /// Base-token fees claimed into the base treasury
+    pub base_amount: u64,
+    pub timestamp: i64,
+}
+
+/// Reason an investor entry was skipped during a payout page
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SkipReason {
+    /// Stream account could not be parsed
+    InvalidStreamAccount,
+    /// Investor quote ATA is not a valid token account of the quote mint and stream recipient
+    InvalidInvestorAta,
+    /// Investor quote ATA does not exist and was not created
+    MissingInvestorAta,
+    /// Trailing stream account without a paired ATA (no longer emitted: such pages fail with `MissingAccountPair`)
+    MissingAccountPair,
+    /// Stream already paid today (payout receipt exists)
+    AlreadyPaidToday,
+    /// Stream vests a mint other than the vault's allocation mint
+    StreamMintMismatch,
+    /// Stream was created by a sender other than the vault's stream sender
+    ForeignStream,
+    /// Investor quote ATA is frozen and cannot receive the payout
+    FrozenInvestorAta,
+    /// Payout fell below the day's minimum and was withheld as dust
+    BelowMinPayout,
+}
+
+impl SkipReason {
+    /// Error recorded for a skip that means a payout could not be made (None when it was not owed)
+    pub fn failure(&self) -> Option<HonoraryFeeError> {
+        match self {
+            SkipReason::InvalidStreamAccount => Some(HonoraryFeeError::InvalidStreamAccount),
+            SkipReason::InvalidInvestorAta => Some(HonoraryFeeError::InvalidInvestorAta),
+            SkipReason::MissingInvestorAta => Some(HonoraryFeeError::MissingInvestorAta),
+            SkipReason::MissingAccountPair => Some(HonoraryFeeError::MissingAccountPair),
+            SkipReason::AlreadyPaidToday => None,
+            SkipReason::StreamMintMismatch => Some(HonoraryFeeError::StreamMintMismatch),
+            SkipReason::ForeignStream => Some(HonoraryFeeError::ForeignStream),
+            SkipReason::FrozenInvestorAta => Some(HonoraryFeeError::InvestorAtaFrozen),
+            SkipReason::BelowMinPayout => None,
+        }
+    }
+}
+
+/// Investor entry skipped during a payout page
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SkippedInvestorEntry {
+    /// Index of the investor within the day's investor list
+    pub index: u64,
+    pub stream_account: Pubkey,
+    pub reason: SkipReason,
+}
+
+/// Event emitted for each investor a payout page skipped or withheld, ahead of its `InvestorPayoutPage`
+#[event]
+pub struct InvestorSkipped {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    /// Index of the investor within the day's investor list
+    pub index: u64,
+    pub stream_account: Pubkey,
+    pub reason: SkipReason,
+    /// Error code matching `reason` when a payout was owed but could not be made, 0 otherwise
+    pub error_code: u32,
     pub timestamp: i64,
 }
 
@@ -23,10 +144,19 @@ pub struct QuoteFeesClaimed {
 #[event] 
 pub struct InvestorPayoutPage {
     pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
     pub page_start: u64,
     pub page_end: u64,
     pub total_distributed: u64,
+    /// Creator share paid with this page (per-page creator payouts only)
+    pub creator_distributed: u64,
+    /// Decimals of the quote mint paid out, for rendering the amounts
+    pub quote_decimals: u8,
     pub investor_count: u64,
+    pub skipped: Vec<SkippedInvestorEntry>,
     pub timestamp: i64,
 }
 
@@ -34,8 +164,343 @@ pub struct InvestorPayoutPage {
 #[event]
 pub struct CreatorPayoutDayClosed {
     pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
     pub creator_amount: u64,
     pub total_claimed_today: u64,
+    /// Decimals of the quote mint paid out, for rendering the amounts
+    pub quote_decimals: u8,
+    pub donations_today: u64,
     pub total_distributed_to_investors: u64,
+    pub dust_withheld_today: u64,
+    /// Investor share clipped by the daily cap and carried into the next day
+    pub capped_excess_carried: u64,
+    /// Route the policy applies to the day's rounding residue
+    pub residue_destination: ResidueDestination,
+    /// Rounding residue moved out of the creator amount to `residue_destination`
+    pub residue_routed: u64,
+    /// Whether the creator amount went to the vesting escrow instead of the creator ATA
+    pub creator_escrowed: bool,
+    /// Whether the creator amount accrued to `creator_pending` instead of the creator ATA (by policy or because the ATA was frozen)
+    pub creator_deferred: bool,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a day close fails the conservation invariant (logged by the failed transaction)
+#[event]
+pub struct ConservationViolated {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    /// Claimed fees, prior carry-over and donations of the day
+    pub total_available: u64,
+    pub distributed_to_investors: u64,
+    pub creator_amount: u64,
+    /// Carry-over the close would leave for the next day
+    pub carried_over: u64,
+    /// Dust and routed residue left in the treasury
+    pub withheld: u64,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a vault is sunset and its deposit refunded
+#[event]
+pub struct VaultSunset {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub depositor: Pubkey,
+    pub refunded_lamports: u64,
+    pub timestamp: i64,
+}
+
+/// Event emitted when quote tokens are donated to the treasury
+#[event]
+pub struct DonationReceived {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub donor: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Event emitted when the dust ledger is swept
+#[event]
+pub struct DustSwept {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub amount: u64,
+    pub destination: DustDestination,
+    pub timestamp: i64,
+}
+/// Event emitted when the creator acknowledges creator-only payouts
+#[event]
+pub struct CreatorOnlyAcknowledged {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub creator: Pubkey,
+    pub creator_only_streak: u16,
+    pub timestamp: i64,
+}
+
+/// Event emitted when the creator claims vested escrow
+#[event]
+pub struct CreatorEscrowClaimed {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub amount: u64,
+    pub remaining_unvested: u64,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a vault migrates to a new quote mint
+#[event]
+pub struct QuoteMintMigrated {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub old_quote_mint: Pubkey,
+    pub new_quote_mint: Pubkey,
+    pub new_treasury: Pubkey,
+    pub new_position: Pubkey,
+    pub residual_to_creator: u64,
+    pub timestamp: i64,
+}
+
+/// Event emitted when an investor is added to the registry
+#[event]
+pub struct InvestorRegistered {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub index: u64,
+    pub stream: Pubkey,
+    pub quote_ata: Pubkey,
+}
+
+/// Event emitted when the locked-amount snapshot covers every registered investor
+#[event]
+pub struct LockedSnapshotCompleted {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub investor_count: u64,
+    pub total_locked: u64,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a pull-claim day's allocation root is finalized
+#[event]
+pub struct ClaimRootFinalized {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub day_ts: i64,
+    pub root: [u8; 32],
+    pub leaf_count: u64,
+    pub total_allocated: u64,
+    /// Locked total the allocations were computed from
+    pub total_locked: u64,
+    /// Locked-weighted investor share of the day, in basis points
+    pub eligible_share_bps: u16,
+    pub timestamp: i64,
+}
+
+/// Event emitted when an investor claims an allocation with a proof
+#[event]
+pub struct InvestorClaimed {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub day_ts: i64,
+    pub index: u64,
+    pub investor_quote_ata: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Event emitted when the policy authority cancels a day in progress
+#[event]
+pub struct DayCancelled {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub day_ts: i64,
+    pub investors_paid: u64,
+    pub distributed_before_cancel: u64,
+    pub carried_over: u64,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a second quote mint is enabled for a vault
+#[event]
+pub struct SecondaryQuoteEnabled {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub secondary_quote_mint: Pubkey,
+    pub secondary_treasury: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when the policy authority proposes a successor
+#[event]
+pub struct AuthorityTransferProposed {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a proposed policy authority accepts
+#[event]
+pub struct AuthorityTransferred {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when the policy authority changes the guardian
+#[event]
+pub struct GuardianUpdated {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub previous_guardian: Pubkey,
+    pub new_guardian: Pubkey,
     pub timestamp: i64,
-}
\ No newline at end of file
+}
+
+/// Previous and new value of a policy parameter changed by `update_policy`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PolicyChange {
+    InvestorFeeShareBps { previous: u16, new: u16 },
+    DailyCap { previous: u64, new: u64 },
+    MinPayoutMode { previous: MinPayoutMode, new: MinPayoutMode },
+    MinPayoutLamports { previous: u64, new: u64 },
+    MinPayoutBps { previous: u16, new: u16 },
+    DistributionPeriod { previous: i64, new: i64 },
+}
+
+/// Event emitted when the policy authority changes policy parameters
+#[event]
+pub struct PolicyUpdated {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub authority: Pubkey,
+    /// Every parameter whose value changed, in `UpdatePolicyParams` order
+    pub changes: Vec<PolicyChange>,
+    pub timestamp: i64,
+}
+
+/// Event emitted when distributions are paused
+#[event]
+pub struct VaultPaused {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub reason: PauseReason,
+    pub paused_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when distributions resume
+#[event]
+pub struct VaultUnpaused {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub previous_reason: PauseReason,
+    pub timestamp: i64,
+}
+
+/// Event emitted when the creator claims remainders accrued under deferred payouts
+#[event]
+pub struct CreatorFeesClaimed {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub quote_mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a vault's state accounts are grown to the current layouts
+#[event]
+pub struct StateUpgraded {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub previous_policy_version: u8,
+    pub previous_progress_version: u8,
+    pub policy_len: u32,
+    pub progress_len: u32,
+    pub timestamp: i64,
+}
+
+/// Event emitted when the streams lock more than the investor allocation and the locked fraction is capped at 100%
+#[event]
+pub struct LockedFractionClamped {
+    pub vault: Pubkey,
+    /// Distribution day the event belongs to
+    pub day_id: u64,
+    /// Sequence number of the event among those emitted against its progress account
+    pub event_seq: u64,
+    pub total_locked: u64,
+    pub total_investor_allocation: u64,
+    pub timestamp: i64,
+}
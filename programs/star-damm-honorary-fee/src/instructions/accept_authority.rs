@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    events::AuthorityTransferred,
+};
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    /// Proposed policy authority
+    pub pending_authority: Signer<'info>,
+    
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump = policy.bump,
+        has_one = pending_authority @ HonoraryFeeError::Unauthorized
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), PROGRESS_SEED],
+        bump = progress.load()?.bump
+    )]
+    pub progress: AccountLoader<'info, ProgressState>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+    let previous_authority = policy.authority;
+    policy.authority = policy.pending_authority;
+    policy.pending_authority = Pubkey::default();
+    
+    let mut progress = ctx.accounts.progress.load_mut()?;
+    emit!(AuthorityTransferred {
+        vault: ctx.accounts.vault.key(),
+        day_id: progress.day_id,
+        event_seq: progress.next_event_seq(),
+        previous_authority,
+        new_authority: policy.authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Policy authority transferred to {}", policy.authority);
+    
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    events::CreatorOnlyAcknowledged,
+};
+
+#[derive(Accounts)]
+pub struct AcknowledgeCreatorOnly<'info> {
+    /// Owner of the creator quote ATA
+    pub creator: Signer<'info>,
+    
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state account
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump = policy.bump
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), PROGRESS_SEED],
+        bump = progress.load()?.bump
+    )]
+    pub progress: AccountLoader<'info, ProgressState>,
+    
+    /// Creator's quote token account, proving the signer is the creator
+    #[account(
+        constraint = creator_quote_ata.key() == policy.creator_quote_ata @ HonoraryFeeError::InvalidTreasury,
+        constraint = creator_quote_ata.owner == creator.key() @ HonoraryFeeError::Unauthorized
+    )]
+    pub creator_quote_ata: Account<'info, TokenAccount>,
+}
+
+pub fn handler(ctx: Context<AcknowledgeCreatorOnly>) -> Result<()> {
+    let mut progress = ctx.accounts.progress.load_mut()?;
+    require!(
+        progress.creator_only_streak > 0,
+        HonoraryFeeError::NoCreatorOnlyStreak
+    );
+    
+    // Holds until a day closes with locked investors again
+    progress.set_creator_only_acknowledged(true);
+    
+    emit!(CreatorOnlyAcknowledged {
+        vault: ctx.accounts.vault.key(),
+        day_id: progress.day_id,
+        event_seq: progress.next_event_seq(),
+        creator: ctx.accounts.creator.key(),
+        creator_only_streak: progress.creator_only_streak,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Creator acknowledged {} creator-only days", progress.creator_only_streak);
+    
+    Ok(())
+}
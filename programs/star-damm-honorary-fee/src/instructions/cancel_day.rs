@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    events::DayCancelled,
+    utils::MathUtil,
+};
+
+#[derive(Accounts)]
+pub struct CancelDay<'info> {
+    /// Policy authority
+    pub authority: Signer<'info>,
+    
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state account
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump = policy.bump,
+        has_one = authority @ HonoraryFeeError::Unauthorized
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), PROGRESS_SEED],
+        bump = progress.load()?.bump
+    )]
+    pub progress: AccountLoader<'info, ProgressState>,
+    
+    /// Dashboard summary account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), SUMMARY_SEED],
+        bump = summary.bump
+    )]
+    pub summary: Account<'info, VaultSummary>,
+}
+
+pub fn handler(ctx: Context<CancelDay>) -> Result<()> {
+    let mut progress = ctx.accounts.progress.load_mut()?;
+    require!(!progress.is_day_complete(), HonoraryFeeError::DistributionComplete);
+    
+    // Everything not yet paid out (dust and per-page creator payouts aside) rolls into the next day
+    let rolled_over = MathUtil::safe_sub(
+        progress.remaining_for_distribution()?,
+        MathUtil::safe_add(progress.daily_dust, progress.creator_paid_today)?
+    )?;
+    
+    progress.carry_over = rolled_over;
+    progress.set_day_complete(true);
+    progress.set_claimed_this_day(false);
+    
+    ctx.accounts.summary.refresh(&ctx.accounts.policy, &progress, 0);
+    
+    let vault_key = ctx.accounts.vault.key();
+    emit!(DayCancelled {
+        vault: vault_key,
+        day_id: progress.day_id,
+        event_seq: progress.next_event_seq(),
+        day_ts: progress.last_distribution_ts,
+        investors_paid: progress.processed_count,
+        distributed_before_cancel: progress.daily_distributed,
+        carried_over: rolled_over,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Vault {} day cancelled, {} carried over", vault_key, rolled_over);
+    
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token,
+    token::{self, Token, TokenAccount, Transfer},
+};
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    events::CreatorEscrowClaimed,
+};
+
+#[derive(Accounts)]
+pub struct ClaimCreator<'info> {
+    /// Owner of the creator quote ATA
+    pub creator: Signer<'info>,
+    
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state account
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump = policy.bump
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state account holding the creator escrow ledger
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), PROGRESS_SEED],
+        bump = progress.load()?.bump
+    )]
+    pub progress: AccountLoader<'info, ProgressState>,
+    
+    /// PDA that owns the treasury
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), INVESTOR_FEE_POS_OWNER_SEED],
+        bump = policy.position_owner_bump
+    )]
+    /// CHECK: This is a PDA
+    pub position_owner_pda: UncheckedAccount<'info>,
+    
+    /// Treasury account holding the escrowed remainder
+    #[account(
+        mut,
+        seeds = [position_owner_pda.key().as_ref(), token_program.key().as_ref(), policy.quote_mint.as_ref()],
+        seeds::program = associated_token::ID,
+        bump = policy.treasury_bump,
+        token::mint = policy.quote_mint,
+        token::authority = position_owner_pda,
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    /// Creator's quote token account
+    #[account(
+        mut,
+        constraint = creator_quote_ata.key() == policy.creator_quote_ata @ HonoraryFeeError::InvalidTreasury,
+        constraint = creator_quote_ata.owner == creator.key() @ HonoraryFeeError::Unauthorized
+    )]
+    pub creator_quote_ata: Account<'info, TokenAccount>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ClaimCreator>) -> Result<()> {
+    let current_ts = Clock::get()?.unix_timestamp;
+    let mut progress = ctx.accounts.progress.load_mut()?;
+    
+    progress.checkpoint_creator_escrow(current_ts)?;
+    let amount = progress.creator_escrow_vested;
+    require!(amount > 0, HonoraryFeeError::NothingToClaim);
+    progress.creator_escrow_vested = 0;
+    progress.treasury_checkpoint = progress.treasury_checkpoint.saturating_sub(amount);
+    
+    let vault_key = ctx.accounts.vault.key();
+    let seeds = &[
+        VAULT_SEED,
+        vault_key.as_ref(),
+        INVESTOR_FEE_POS_OWNER_SEED,
+        &[ctx.accounts.policy.position_owner_bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.treasury.to_account_info(),
+        to: ctx.accounts.creator_quote_ata.to_account_info(),
+        authority: ctx.accounts.position_owner_pda.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    token::transfer(cpi_ctx, amount)?;
+    
+    emit!(CreatorEscrowClaimed {
+        vault: vault_key,
+        day_id: progress.day_id,
+        event_seq: progress.next_event_seq(),
+        amount,
+        remaining_unvested: progress.creator_escrow_unvested,
+        timestamp: current_ts,
+    });
+    
+    msg!("Creator claimed {} vested quote tokens", amount);
+    
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token,
+    token::{self, Token, TokenAccount, Transfer},
+};
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    events::CreatorFeesClaimed,
+};
+
+#[derive(Accounts)]
+pub struct ClaimCreatorFees<'info> {
+    /// Owner of the creator quote ATA
+    pub creator: Signer<'info>,
+    
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state account
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump = policy.bump
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state account of the treasury's quote mint, holding the pending balance
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), policy.progress_seed_for(&treasury.mint)],
+        bump = progress.load()?.bump
+    )]
+    pub progress: AccountLoader<'info, ProgressState>,
+    
+    /// PDA that owns the treasury
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), INVESTOR_FEE_POS_OWNER_SEED],
+        bump = policy.position_owner_bump
+    )]
+    /// CHECK: This is a PDA
+    pub position_owner_pda: UncheckedAccount<'info>,
+    
+    /// Treasury account holding the accrued remainders
+    #[account(
+        mut,
+        constraint = policy.is_quote_mint(&treasury.mint) @ HonoraryFeeError::InvalidQuoteMint,
+        seeds = [position_owner_pda.key().as_ref(), token_program.key().as_ref(), treasury.mint.as_ref()],
+        seeds::program = associated_token::ID,
+        bump = policy.treasury_bump_for(&treasury.mint),
+        token::mint = treasury.mint,
+        token::authority = position_owner_pda,
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    /// Creator's token account for the treasury's quote mint
+    #[account(
+        mut,
+        constraint = creator_quote_ata.key() == policy.creator_quote_ata_for(&treasury.mint) @ HonoraryFeeError::InvalidTreasury,
+        constraint = creator_quote_ata.owner == creator.key() @ HonoraryFeeError::Unauthorized
+    )]
+    pub creator_quote_ata: Account<'info, TokenAccount>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+    let mut progress = ctx.accounts.progress.load_mut()?;
+    
+    // Remainders accrued before deferral was switched off stay claimable
+    let amount = progress.creator_pending;
+    require!(amount > 0, HonoraryFeeError::NothingToClaim);
+    progress.creator_pending = 0;
+    progress.treasury_checkpoint = progress.treasury_checkpoint.saturating_sub(amount);
+    
+    let vault_key = ctx.accounts.vault.key();
+    let seeds = &[
+        VAULT_SEED,
+        vault_key.as_ref(),
+        INVESTOR_FEE_POS_OWNER_SEED,
+        &[ctx.accounts.policy.position_owner_bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.treasury.to_account_info(),
+        to: ctx.accounts.creator_quote_ata.to_account_info(),
+        authority: ctx.accounts.position_owner_pda.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    token::transfer(cpi_ctx, amount)?;
+    
+    emit!(CreatorFeesClaimed {
+        vault: vault_key,
+        day_id: progress.day_id,
+        event_seq: progress.next_event_seq(),
+        quote_mint: ctx.accounts.treasury.mint,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Creator claimed {} accrued quote tokens", amount);
+    
+    Ok(())
+}
@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token,
+    token::{self, Token, TokenAccount, Transfer},
+};
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    events::InvestorClaimed,
+    utils::{MathUtil, MerkleUtil},
+};
+
+#[derive(Accounts)]
+pub struct ClaimWithProof<'info> {
+    /// Anyone may submit a claim; funds only go to the committed quote ATA
+    pub claimer: Signer<'info>,
+    
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state account
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump = policy.bump
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), PROGRESS_SEED],
+        bump = progress.load()?.bump
+    )]
+    pub progress: AccountLoader<'info, ProgressState>,
+    
+    /// The day's claim distribution
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            CLAIM_DISTRIBUTION_SEED,
+            &claim_distribution.day_ts.to_le_bytes(),
+        ],
+        bump = claim_distribution.bump
+    )]
+    pub claim_distribution: Account<'info, ClaimDistribution>,
+    
+    /// Lifetime statistics account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), STATS_SEED],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, VaultStats>,
+    
+    /// PDA that owns the treasury
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), INVESTOR_FEE_POS_OWNER_SEED],
+        bump = policy.position_owner_bump
+    )]
+    /// CHECK: This is a PDA
+    pub position_owner_pda: UncheckedAccount<'info>,
+    
+    /// Treasury account holding unclaimed allocations
+    #[account(
+        mut,
+        seeds = [position_owner_pda.key().as_ref(), token_program.key().as_ref(), policy.quote_mint.as_ref()],
+        seeds::program = associated_token::ID,
+        bump = policy.treasury_bump,
+        token::mint = policy.quote_mint,
+        token::authority = position_owner_pda,
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    /// Investor's quote token account named in the allocation leaf
+    #[account(
+        mut,
+        token::mint = policy.quote_mint
+    )]
+    pub investor_quote_ata: Account<'info, TokenAccount>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<ClaimWithProof>,
+    index: u64,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let distribution = &mut ctx.accounts.claim_distribution;
+    let investor_quote_ata = ctx.accounts.investor_quote_ata.key();
+    
+    require!(index < distribution.leaf_count, HonoraryFeeError::InvalidMerkleProof);
+    require!(!distribution.is_claimed(index), HonoraryFeeError::AlreadyClaimed);
+    
+    let leaf = MerkleUtil::claim_leaf(index, &investor_quote_ata, amount);
+    require!(
+        MerkleUtil::verify(&proof, &distribution.root, leaf),
+        HonoraryFeeError::InvalidMerkleProof
+    );
+    
+    distribution.set_claimed(index);
+    distribution.total_claimed = MathUtil::safe_add(distribution.total_claimed, amount)?;
+    require!(
+        distribution.total_claimed <= distribution.total_allocated,
+        HonoraryFeeError::ClaimAllocationExceedsCap
+    );
+    
+    let mut progress = ctx.accounts.progress.load_mut()?;
+    progress.claims_outstanding = MathUtil::safe_sub(progress.claims_outstanding, amount)?;
+    
+    let stats = &mut ctx.accounts.stats;
+    stats.total_paid_to_investors = MathUtil::safe_add(stats.total_paid_to_investors, amount)?;
+    
+    let vault_key = ctx.accounts.vault.key();
+    let seeds = &[
+        VAULT_SEED,
+        vault_key.as_ref(),
+        INVESTOR_FEE_POS_OWNER_SEED,
+        &[ctx.accounts.policy.position_owner_bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.treasury.to_account_info(),
+        to: ctx.accounts.investor_quote_ata.to_account_info(),
+        authority: ctx.accounts.position_owner_pda.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    let treasury_before = ctx.accounts.treasury.amount;
+    token::transfer(cpi_ctx, amount)?;
+    
+    // The claim must leave the treasury exactly, like every other outflow
+    ctx.accounts.treasury.reload()?;
+    progress.record_treasury_outflow(treasury_before, ctx.accounts.treasury.amount, amount)?;
+    
+    emit!(InvestorClaimed {
+        vault: vault_key,
+        day_id: distribution.day_id,
+        event_seq: progress.next_event_seq(),
+        day_ts: distribution.day_ts,
+        index,
+        investor_quote_ata,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -1,13 +1,20 @@
+use std::ops::Range;
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount};
 use anchor_spl::{
-    token::{self, Token, TokenAccount, Transfer},
+    associated_token::{self, AssociatedToken, Create},
+    token::{self, Mint, Token, TokenAccount, Transfer},
+    token_interface::TokenAccount as InterfaceTokenAccount,
 };
 use crate::{
     state::*,
     error::HonoraryFeeError,
     events::*,
-    utils::MathUtil,
-    validation::PoolValidator,
+    utils::{MathUtil, MerkleUtil},
+    validation::{CpAmmPool, PoolValidator},
+    enhanced_utils::{EnhancedMathUtil, ValidationUtil},
+    streamflow_layout::StreamContract,
+    cp_amm_cpi::{require_claim_received, verify_position_authority, ClaimPositionFee, PositionFees},
 };
 
 #[derive(Accounts)]
@@ -15,59 +22,102 @@ pub struct DistributeFees<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     
+    /// Program-wide configuration
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+    
     /// The vault identifier
     /// CHECK: Used as seed for PDA derivation
     pub vault: UncheckedAccount<'info>,
     
-    /// Policy state account
+    /// Policy state account, paused by the day-start crank when base fees are quarantined
     #[account(
+        mut,
         seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
         bump = policy.bump
     )]
     pub policy: Account<'info, PolicyState>,
     
-    /// Progress state account
+    /// Progress state account of the quote mint being distributed
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), policy.progress_seed_for(&treasury.mint)],
+        bump = progress.load()?.bump
+    )]
+    pub progress: AccountLoader<'info, ProgressState>,
+    
+    /// Dashboard summary account, refreshed at day close
     #[account(
         mut,
-        seeds = [VAULT_SEED, vault.key().as_ref(), PROGRESS_SEED],
-        bump = progress.bump
+        seeds = [VAULT_SEED, vault.key().as_ref(), SUMMARY_SEED],
+        bump = summary.bump
     )]
-    pub progress: Account<'info, ProgressState>,
+    pub summary: Account<'info, VaultSummary>,
+    
+    /// Lifetime statistics account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), STATS_SEED],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, VaultStats>,
     
     /// PDA that owns the honorary position
     #[account(
         seeds = [VAULT_SEED, vault.key().as_ref(), INVESTOR_FEE_POS_OWNER_SEED],
-        bump
+        bump = policy.position_owner_bump
     )]
     /// CHECK: This is a PDA
     pub position_owner_pda: UncheckedAccount<'info>,
     
     /// Honorary position account
-    /// CHECK: Validated through cp-amm integration
-    #[account(mut)]
+    /// CHECK: Must be the vault's position; its NFT holder is checked in handler
+    #[account(
+        mut,
+        address = policy.position @ HonoraryFeeError::InvalidPositionOwner,
+        owner = policy.cp_amm_program @ HonoraryFeeError::InvalidPositionOwner
+    )]
     pub position: UncheckedAccount<'info>,
     
-    /// Treasury account for holding claimed fees
+    /// Token account holding the position NFT, which carries the position's authority
+    pub position_nft_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// cp-amm pool the fees are claimed from
+    /// CHECK: Must be the vault's pool; re-validated for quote-only accrual on each claim
+    #[account(address = policy.pool @ HonoraryFeeError::PoolMismatch)]
+    pub pool: UncheckedAccount<'info>,
+    
+    /// Treasury of the quote mint being distributed
     #[account(
         mut,
-        associated_token::mint = policy.quote_mint,
-        associated_token::authority = position_owner_pda,
+        constraint = policy.is_quote_mint(&treasury.mint) @ HonoraryFeeError::InvalidQuoteMint,
+        constraint = treasury.delegate.is_none() && treasury.close_authority.is_none() @ HonoraryFeeError::TreasuryEncumbered,
+        seeds = [position_owner_pda.key().as_ref(), token_program.key().as_ref(), treasury.mint.as_ref()],
+        seeds::program = associated_token::ID,
+        bump = policy.treasury_bump_for(&treasury.mint),
+        token::mint = treasury.mint,
+        token::authority = position_owner_pda,
     )]
     pub treasury: Account<'info, TokenAccount>,
     
-    /// Creator's quote token account
+    /// Creator's token account for the quote mint being distributed
     #[account(
         mut,
-        constraint = creator_quote_ata.key() == policy.creator_quote_ata @ HonoraryFeeError::InvalidTreasury
+        constraint = creator_quote_ata.key() == policy.creator_quote_ata_for(&treasury.mint) @ HonoraryFeeError::InvalidTreasury
     )]
     pub creator_quote_ata: Account<'info, TokenAccount>,
     
     /// cp-amm program
-    /// CHECK: This is the cp-amm program ID
+    /// CHECK: Must be the cp-amm program recorded in the policy
+    #[account(address = policy.cp_amm_program @ HonoraryFeeError::ProgramIdMismatch)]
     pub cp_amm_program: UncheckedAccount<'info>,
     
     /// Streamflow program
-    /// CHECK: This is the Streamflow program ID
+    /// CHECK: Must be the Streamflow program recorded in the policy
+    #[account(address = policy.streamflow_program @ HonoraryFeeError::ProgramIdMismatch)]
     pub streamflow_program: UncheckedAccount<'info>,
     
     /// Token program
@@ -76,338 +126,1596 @@ pub struct DistributeFees<'info> {
     /// System program
     pub system_program: Program<'info, System>,
     
-    /// Clock sysvar
-    pub clock: Sysvar<'info, Clock>,
+    /// Quote mint being distributed, required only when creating missing investor ATAs
+    #[account(address = treasury.mint @ HonoraryFeeError::InvalidQuoteMint)]
+    pub quote_mint: Option<Account<'info, Mint>>,
+    
+    /// Associated token program, required only when creating missing investor ATAs
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    
+    /// Investor registry, required when the policy enables it
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), REGISTRY_SEED],
+        bump = investor_registry.bump
+    )]
+    pub investor_registry: Option<Account<'info, InvestorRegistry>>,
+    
+    /// Locked-amount snapshot used for the whole day, required with the registry
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), SNAPSHOT_SEED],
+        bump = locked_snapshot.bump
+    )]
+    pub locked_snapshot: Option<Account<'info, LockedSnapshot>>,
+    
+    /// Treasury of the other quote mint, required on day start when a secondary quote mint is enabled
+    #[account(
+        mut,
+        constraint = policy.paired_quote_mint(&treasury.mint) == Some(paired_treasury.mint) @ HonoraryFeeError::InvalidQuoteMint,
+        constraint = paired_treasury.delegate.is_none() && paired_treasury.close_authority.is_none() @ HonoraryFeeError::TreasuryEncumbered,
+        associated_token::mint = paired_treasury.mint,
+        associated_token::authority = position_owner_pda,
+    )]
+    pub paired_treasury: Option<Account<'info, TokenAccount>>,
+    
+    /// Progress state of the other quote mint, credited with the fees claimed in that mint
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), policy.paired_progress_seed_for(&treasury.mint)],
+        bump = paired_progress.load()?.bump
+    )]
+    pub paired_progress: Option<AccountLoader<'info, ProgressState>>,
+    
+    /// Base treasury quarantining any base fees the claim returns, required by single-quote day starts
+    #[account(
+        mut,
+        constraint = base_treasury.mint == policy.base_mint @ HonoraryFeeError::InvalidQuoteMint,
+        token::authority = position_owner_pda,
+        constraint = base_treasury.delegate.is_none() && base_treasury.close_authority.is_none() @ HonoraryFeeError::TreasuryEncumbered,
+    )]
+    pub base_treasury: Option<Account<'info, TokenAccount>>,
+    
+    /// cp-amm pool authority, required on day start to claim the position's fees
+    /// CHECK: Checked by cp-amm against its pool authority PDA
+    pub pool_authority: Option<UncheckedAccount<'info>>,
+    
+    /// Pool vault of token A, required on day start
+    /// CHECK: Must be the pool's token A vault; checked against the parsed pool
+    #[account(mut)]
+    pub token_a_vault: Option<UncheckedAccount<'info>>,
+    
+    /// Pool vault of token B, required on day start
+    /// CHECK: Must be the pool's token B vault; checked against the parsed pool
+    #[account(mut)]
+    pub token_b_vault: Option<UncheckedAccount<'info>>,
+    
+    /// Pool token A mint, required on day start
+    /// CHECK: Must be the pool's token A mint; checked against the parsed pool
+    pub token_a_mint: Option<UncheckedAccount<'info>>,
+    
+    /// Pool token B mint, required on day start
+    /// CHECK: Must be the pool's token B mint; checked against the parsed pool
+    pub token_b_mint: Option<UncheckedAccount<'info>>,
+    
+    /// cp-amm event authority, required on day start
+    /// CHECK: Checked by cp-amm against its event authority PDA
+    pub event_authority: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> DistributeFees<'info> {
+    /// Accounts of the cp-amm `claim_position_fee` CPI, sending the quote leg to the treasury
+    /// and the other leg to the base treasury (or the paired treasury with two quote mints)
+    fn claim_position_fee(&self, pool: &CpAmmPool) -> Result<ClaimPositionFee<'info>> {
+        let (
+            Some(pool_authority),
+            Some(token_a_vault),
+            Some(token_b_vault),
+            Some(token_a_mint),
+            Some(token_b_mint),
+            Some(event_authority),
+        ) = (
+            &self.pool_authority,
+            &self.token_a_vault,
+            &self.token_b_vault,
+            &self.token_a_mint,
+            &self.token_b_mint,
+            &self.event_authority,
+        ) else {
+            return err!(HonoraryFeeError::MissingClaimAccounts);
+        };
+        require!(
+            token_a_vault.key() == pool.token_a_vault
+                && token_b_vault.key() == pool.token_b_vault
+                && token_a_mint.key() == pool.token_a_mint
+                && token_b_mint.key() == pool.token_b_mint,
+            HonoraryFeeError::PoolMismatch
+        );
+        
+        let other_account = match (&self.paired_treasury, &self.base_treasury) {
+            (Some(paired_treasury), _) if self.policy.has_secondary_quote() => paired_treasury.to_account_info(),
+            (_, Some(base_treasury)) => base_treasury.to_account_info(),
+            _ => return err!(HonoraryFeeError::MissingBaseTreasury),
+        };
+        let (token_a_account, token_b_account) = pool
+            .orient_accounts(&self.treasury.mint, self.treasury.to_account_info(), other_account)
+            .ok_or(HonoraryFeeError::InvalidQuoteMint)?;
+        
+        Ok(ClaimPositionFee {
+            pool_authority: pool_authority.to_account_info(),
+            pool: self.pool.to_account_info(),
+            position: self.position.to_account_info(),
+            token_a_account,
+            token_b_account,
+            token_a_vault: token_a_vault.to_account_info(),
+            token_b_vault: token_b_vault.to_account_info(),
+            token_a_mint: token_a_mint.to_account_info(),
+            token_b_mint: token_b_mint.to_account_info(),
+            position_nft_account: self.position_nft_account.to_account_info(),
+            owner: self.position_owner_pda.to_account_info(),
+            token_a_program: self.token_program.to_account_info(),
+            token_b_program: self.token_program.to_account_info(),
+            event_authority: event_authority.to_account_info(),
+            program: self.cp_amm_program.to_account_info(),
+        })
+    }
+    
+
+    fn day_close(&mut self, position_owner_bump: u8) -> DayClose<'_, 'info> {
+        DayClose {
+            vault: self.vault.key(),
+            policy: &self.policy,
+            progress: &self.progress,
+            summary: &mut self.summary,
+            stats: &mut self.stats,
+            treasury: self.treasury.to_account_info(),
+            quote_decimals: self.policy.quote_decimals_for(&self.treasury.mint),
+            creator_quote_ata: self.creator_quote_ata.to_account_info(),
+            position_owner_pda: self.position_owner_pda.to_account_info(),
+            position_owner_bump,
+            token_program: self.token_program.to_account_info(),
+        }
+    }
 }
 
 // Additional accounts for investor distribution (passed as remaining accounts)
 #[derive(Clone)]
-pub struct InvestorDistributionAccount {
+pub struct InvestorDistributionAccount<'info> {
+    /// Index of the investor within the day's investor list
+    pub index: u64,
     /// Streamflow stream account
     pub stream_account: Pubkey,
     /// Investor's quote token account
-    pub investor_quote_ata: Pubkey,
+    pub investor_quote_ata: AccountInfo<'info>,
+    /// Investor wallet owning the quote ATA (only supplied when creating missing ATAs)
+    pub investor_owner: Option<AccountInfo<'info>>,
+    /// Investor payout record slot (only supplied when the policy enables payout records)
+    pub payout_record: Option<AccountInfo<'info>>,
+    /// Day-scoped payout receipt slot (only supplied when the policy enables payout receipts)
+    pub payout_receipt: Option<AccountInfo<'info>>,
+    /// Stream recipient that must own the quote ATA (only known when reading live streams)
+    pub recipient: Option<Pubkey>,
+    /// Whether the quote ATA does not exist yet and must be created before the transfer
+    pub create_ata: bool,
     /// Current locked amount (read from Streamflow)
     pub locked_amount: u64,
+    /// Registry weight multiplier (10000 = 1x)
+    pub multiplier_bps: u16,
+    /// Set when the investor ATA failed validation; the investor keeps its weight but is not paid
+    pub skip_reason: Option<SkipReason>,
+}
+
+impl InvestorDistributionAccount<'_> {
+    /// Pro-rata weight: the locked amount scaled by the registry multiplier
+    pub fn weight(&self) -> Result<u64> {
+        let weight = (self.locked_amount as u128)
+            .checked_mul(self.multiplier_bps as u128)
+            .ok_or(HonoraryFeeError::ArithmeticOverflow)?
+            / BASE_MULTIPLIER_BPS as u128;
+        u64::try_from(weight).map_err(|_| error!(HonoraryFeeError::ArithmeticOverflow))
+    }
+}
+
+/// Locked amount and Merkle proof for one investor of a Merkle-mode page
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct InvestorProof {
+    pub locked_amount: u64,
+    pub proof: Vec<[u8; 32]>,
+}
+
+/// Investor list parsed from remaining accounts, with entries that could not be parsed
+pub struct ParsedInvestors<'info> {
+    pub investors: Vec<InvestorDistributionAccount<'info>>,
+    pub skipped: Vec<SkippedInvestorEntry>,
+    /// Number of investor slots for the day (including skipped ones)
+    pub investor_count: u64,
+    /// Locked amount across every investor of the day
+    pub total_locked: u64,
 }
 
-pub fn handler(ctx: Context<DistributeFees>, page_size: u32) -> Result<()> {
+/// Result of processing one investor page
+pub struct PageResult {
+    pub page_start: u64,
+    pub page_end: u64,
+    pub total_distributed: u64,
+    /// Payouts withheld for being below the minimum payout threshold
+    pub dust: u64,
+    /// Part of `dust` credited to investors' payout records instead of the dust ledger
+    pub investor_dust_credited: u64,
+    /// Dust owed from earlier days and paid out with this page
+    pub investor_dust_released: u64,
+    /// Rounding residue left by flooring the page's payouts
+    pub residue: u64,
+    /// Investors of the page not processed by an earlier page
+    pub investors_processed: u64,
+    pub skipped: Vec<SkippedInvestorEntry>,
+}
+
+pub fn handler<'info>(
+    mut ctx: Context<'_, '_, '_, 'info, DistributeFees<'info>>,
+    page_size: u32,
+    create_missing_atas: bool,
+    investor_set: Option<InvestorSetCommitment>,
+    proofs: Vec<InvestorProof>,
+    page_start: Option<u64>,
+    expected_investor_count: Option<u64>,
+) -> Result<()> {
     let vault = ctx.accounts.vault.key();
-    let current_ts = ctx.accounts.clock.unix_timestamp;
-    let policy = &ctx.accounts.policy;
-    let progress = &mut ctx.accounts.progress;
+    let current_ts = Clock::get()?.unix_timestamp;
     
-    // Check if this is the start of a new day
-    let is_new_day = !progress.day_complete || 
-        MathUtil::is_24h_elapsed(progress.last_distribution_ts, current_ts);
+    require!(!ctx.accounts.policy.sunset, HonoraryFeeError::VaultSunset);
+    if let Some(reason) = ctx.accounts.policy.pause_reason {
+        msg!("Distributions are paused: {:?}", reason);
+        return err!(HonoraryFeeError::VaultPaused);
+    }
+    ValidationUtil::validate_page_size(
+        page_size,
+        ctx.accounts.policy.min_page_size,
+        ctx.accounts.policy.max_page_size,
+    )?;
     
-    // If it's a new day, we need to claim fees first
-    if is_new_day {
+    // Reject pages that would run out of compute midway, after some investors were already paid
+    let compute_cap = ctx.accounts.policy.max_investors_per_page(create_missing_atas);
+    if page_size > compute_cap {
+        msg!("Page size {} exceeds the compute cap of {} investors", page_size, compute_cap);
+        return err!(HonoraryFeeError::PageTooLarge);
+    }
+    
+    // Every investor passed is parsed, including those outside the page, so they count too
+    let accounts_per_investor = 2
+        + create_missing_atas as usize
+        + ctx.accounts.policy.payout_receipts as usize
+        + ctx.accounts.policy.payout_records as usize;
+    let investors_read = (ctx.remaining_accounts.len() / accounts_per_investor) as u64;
+    let page_compute = ctx.accounts.policy.page_compute_units(page_size, investors_read, create_missing_atas);
+    if page_compute > PAGE_INVESTOR_COMPUTE_BUDGET {
+        msg!(
+            "Page of {} investors reading {} needs {} compute units, over the budget of {}",
+            page_size,
+            investors_read,
+            page_compute,
+            PAGE_INVESTOR_COMPUTE_BUDGET
+        );
+        return err!(HonoraryFeeError::PageTooLarge);
+    }
+    
+    // Creating ATAs needs the quote mint and the associated token program
+    if create_missing_atas {
         require!(
-            MathUtil::is_24h_elapsed(progress.last_distribution_ts, current_ts),
-            HonoraryFeeError::CooldownNotElapsed
+            ctx.accounts.quote_mint.is_some() && ctx.accounts.associated_token_program.is_some(),
+            HonoraryFeeError::MissingAtaCreationAccounts
         );
-        
-        // Start new day
-        progress.last_distribution_ts = current_ts;
-        progress.daily_distributed = 0;
-        progress.pagination_cursor = 0;
-        progress.day_complete = false;
-        progress.daily_claimed_total = 0;
+    }
+    
+    // The honorary position must still be controlled by the position owner PDA
+    verify_position_authority(
+        &PositionFees::try_from_bytes(&ctx.accounts.position.try_borrow_data()?)?,
+        &ctx.accounts.position_nft_account,
+        &ctx.accounts.position_owner_pda.key(),
+        &ctx.accounts.policy.pool,
+    )?;
+    
+    // No crank may run while a day start is claiming fees, whatever the day state says
+    require!(
+        !ctx.accounts.progress.load()?.is_day_starting(),
+        HonoraryFeeError::DayStartInProgress
+    );
+    
+    // A clock behind the last day start or crank would make the cooldown, throttle and caps misbehave
+    {
+        let progress = ctx.accounts.progress.load()?;
+        let latest_ts = progress.last_distribution_ts.max(progress.last_crank_ts);
+        if current_ts < latest_ts {
+            msg!("Clock at {} is behind the last recorded crank at {}", current_ts, latest_ts);
+            return err!(HonoraryFeeError::ClockRegression);
+        }
+    }
+    
+    // A new day starts only once the previous day has been closed
+    let is_new_day = ctx.accounts.progress.load()?.is_day_complete();
+    
+    // Pages within a day are throttled so rapid no-op cranks cannot spam events and logs
+    if !is_new_day {
+        let last_crank_ts = ctx.accounts.progress.load()?.last_crank_ts;
+        let interval = ctx.accounts.policy.min_crank_interval_seconds as i64;
+        require!(
+            current_ts >= last_crank_ts.saturating_add(interval),
+            HonoraryFeeError::CrankTooSoon
+        );
+    }
+    
+    // Keeper telemetry so operators can spot stalled days
+    ctx.accounts.progress.load_mut()?.record_crank(ctx.accounts.payer.key(), current_ts, is_new_day)?;
+    
+    // Pull-claim days only start here; they close in finalize_claim_root
+    if ctx.accounts.policy.pull_claims {
+        require!(is_new_day, HonoraryFeeError::ClaimRootPending);
+    }
+    
+    // The investor set can only be committed by the day-start crank
+    require!(
+        investor_set.is_none() || (is_new_day && ctx.accounts.policy.merkle_investor_set),
+        HonoraryFeeError::InvalidInvestorSetCommitment
+    );
+    
+    // The day's investor count is committed once, when the day starts
+    require!(
+        expected_investor_count.is_some() == is_new_day,
+        HonoraryFeeError::InvestorCountMismatch
+    );
+    
+    // With two quote mints, each day start claims both and credits the other mint's progress;
+    // with one, the base leg is claimed into the base treasury so base fees are seen
+    if is_new_day && ctx.accounts.policy.has_secondary_quote() {
+        require!(
+            ctx.accounts.paired_treasury.is_some() && ctx.accounts.paired_progress.is_some(),
+            HonoraryFeeError::MissingPairedQuoteAccounts
+        );
+    } else if is_new_day {
+        require!(ctx.accounts.base_treasury.is_some(), HonoraryFeeError::MissingBaseTreasury);
+    }
+    
+    // If it's a new day, we need to claim fees first
+    if is_new_day {
+        {
+            let mut progress = ctx.accounts.progress.load_mut()?;
+            require!(
+                ctx.accounts.policy.is_day_elapsed(progress.last_distribution_ts, current_ts),
+                HonoraryFeeError::CooldownNotElapsed
+            );
+            
+            // The day's payouts use the snapshot taken since the previous day started
+            if let LockedSource::Snapshot { registry, snapshot, .. } = locked_source(
+                &ctx.accounts.global_config,
+                &ctx.accounts.policy,
+                investor_set,
+                0..page_size as u64,
+                &proofs,
+                &ctx.accounts.investor_registry,
+                &ctx.accounts.locked_snapshot,
+            )? {
+                require!(
+                    snapshot.is_complete_for(registry, &progress),
+                    HonoraryFeeError::SnapshotIncomplete
+                );
+            }
+            
+            // In Merkle mode the policy authority commits the day's investor set
+            if ctx.accounts.policy.merkle_investor_set {
+                let investor_set = investor_set.ok_or(HonoraryFeeError::InvalidInvestorSetCommitment)?;
+                require_keys_eq!(
+                    ctx.accounts.payer.key(),
+                    ctx.accounts.policy.authority,
+                    HonoraryFeeError::Unauthorized
+                );
+                progress.investor_set = investor_set;
+            }
+            
+            // Merkle days cover exactly the committed leaves
+            let expected_investor_count = expected_investor_count.ok_or(HonoraryFeeError::InvestorCountMismatch)?;
+            require!(
+                !ctx.accounts.policy.merkle_investor_set
+                    || expected_investor_count == progress.investor_set.investor_count,
+                HonoraryFeeError::InvestorCountMismatch
+            );
+            require!(
+                expected_investor_count <= ctx.accounts.policy.max_investors as u64,
+                HonoraryFeeError::TooManyInvestors
+            );
+            
+            // Start new day; the day is marked open before the claim so it cannot start twice
+            progress.begin_day_start()?;
+            progress.last_distribution_ts = current_ts;
+            progress.day_id = MathUtil::safe_add(progress.day_id, 1)?;
+            progress.daily_distributed = 0;
+            progress.creator_paid_today = 0;
+            progress.reset_processed();
+            progress.expected_investor_count = expected_investor_count;
+            progress.total_locked_today = 0;
+            progress.eligible_share_bps = 0;
+            progress.set_day_complete(false);
+            progress.daily_claimed_total = progress.pending_claimed;
+            progress.pending_claimed = 0;
+            progress.daily_claimed_base = 0;
+            progress.daily_dust = 0;
+            progress.daily_residue = 0;
+            progress.investor_fee_share_bps = ctx.accounts.policy.investor_fee_share_bps_at(current_ts);
+            progress.daily_cap = ctx.accounts.policy.daily_cap;
+            
+            // Donations received since the last day start are distributed today
+            progress.daily_donations = progress.pending_donations;
+            progress.pending_donations = 0;
+        }
         
         // Claim fees from honorary position
-        claim_fees_from_position(ctx)?;
+        claim_fees_from_position(&mut ctx, current_ts)?;
         
-        msg!("Started new distribution day, claimed {} quote tokens", 
-             progress.daily_claimed_total);
+        // Everything the day moves is checked against the balance after the claim
+        {
+            let mut progress = ctx.accounts.progress.load_mut()?;
+            progress.treasury_checkpoint = ctx.accounts.treasury.amount;
+            progress.end_day_start();
+        }
+        
+        let daily_claimed_total = ctx.accounts.progress.load()?.daily_claimed_total;
+        let stats = &mut ctx.accounts.stats;
+        stats.total_fees_claimed = MathUtil::safe_add(stats.total_fees_claimed, daily_claimed_total)?;
+        
+        msg!("Started new distribution day, claimed {} quote tokens", daily_claimed_total);
+        
+        // A quarantine pauses the vault; its pages wait for the authority to unpause
+        if ctx.accounts.policy.pull_claims || ctx.accounts.policy.pause_reason.is_some() {
+            return Ok(());
+        }
     }
     
     // Process investor distributions
-    let (total_distributed, investors_processed) = process_investor_page(
-        &ctx,
-        page_size,
+    // Pages start at the lowest unprocessed index unless the keeper targets one explicitly
+    let (first_index, committed_set, committed_count) = {
+        let progress = ctx.accounts.progress.load()?;
+        // A closed day takes no more pages; its totals belong to the creator payout already made
+        require!(!progress.is_day_complete(), HonoraryFeeError::DistributionComplete);
+        
+        // A treasury drained outside the program cannot fund the rest of the day; stop before paying anyone
+        let obligation = progress.day_treasury_obligation()?;
+        if ctx.accounts.treasury.amount < obligation {
+            msg!("Treasury holds {}, the day still owes {}", ctx.accounts.treasury.amount, obligation);
+            return err!(HonoraryFeeError::TreasuryMismatch);
+        }
+        (
+            page_start.unwrap_or(progress.pagination_cursor),
+            progress.investor_set,
+            progress.expected_investor_count,
+        )
+    };
+    
+    // Registry and Merkle vaults pay against the day's committed locked amounts
+    let source = locked_source(
+        &ctx.accounts.global_config,
+        &ctx.accounts.policy,
+        Some(committed_set),
+        first_index..MathUtil::safe_add(first_index, page_size as u64)?,
+        &proofs,
+        &ctx.accounts.investor_registry,
+        &ctx.accounts.locked_snapshot,
+    )?;
+    let mut parsed = parse_investor_accounts(
+        ctx.remaining_accounts,
+        &ctx.accounts.treasury.mint,
+        create_missing_atas,
+        ctx.accounts.policy.payout_records,
+        ctx.accounts.policy.payout_receipts,
+        source,
     )?;
     
+    // A truncated investor list cannot close the day early
+    require!(
+        parsed.investor_count == committed_count,
+        HonoraryFeeError::InvestorCountMismatch
+    );
+    ValidationUtil::validate_pagination_cursor(first_index, parsed.investor_count, page_size)?;
+    
+    // The first page fixes the day's denominator and split; later pages reuse them whatever accounts they carry
+    {
+        let mut progress = ctx.accounts.progress.load_mut()?;
+        if is_new_day {
+            progress.total_locked_today = parsed.total_locked;
+            progress.eligible_share_bps = eligible_share_bps(
+                &ctx.accounts.policy,
+                parsed.total_locked,
+                progress.investor_fee_share_bps,
+            )?;
+            progress.min_payout = min_payout_threshold(
+                &ctx.accounts.policy,
+                progress.daily_cap,
+                progress.investor_fee_share_bps,
+                progress.eligible_share_bps,
+                progress.total_available()?,
+            )?;
+            
+            // More locked than allocated means top-ups or a misconfigured allocation
+            let total_investor_allocation = ctx.accounts.policy.total_investor_allocation;
+            if total_investor_allocation > 0 && parsed.total_locked > total_investor_allocation {
+                msg!(
+                    "Locked total {} exceeds investor allocation {}; locked fraction capped at 100%",
+                    parsed.total_locked,
+                    total_investor_allocation
+                );
+                emit!(LockedFractionClamped {
+                    vault,
+                    day_id: progress.day_id,
+                    event_seq: progress.next_event_seq(),
+                    total_locked: parsed.total_locked,
+                    total_investor_allocation,
+                    timestamp: current_ts,
+                });
+            }
+            
+            emit!(DayStarted {
+                vault,
+                day_id: progress.day_id,
+                event_seq: progress.next_event_seq(),
+                claimed_amount: progress.daily_claimed_total,
+                carry_over: progress.carry_over,
+                total_locked: progress.total_locked_today,
+                eligible_share_bps: progress.eligible_share_bps,
+                expected_investor_count: progress.expected_investor_count,
+                timestamp: current_ts,
+            });
+        }
+        parsed.total_locked = progress.total_locked_today;
+    }
+    
+    // Merkle pages carry exactly the investors of the page so none can be passed over
+    if ctx.accounts.policy.merkle_investor_set {
+        let page_end = MathUtil::safe_add(first_index, page_size as u64)?.min(parsed.investor_count);
+        require!(
+            proofs.len() as u64 == page_end.saturating_sub(first_index),
+            HonoraryFeeError::InvalidMerkleProof
+        );
+    }
+    
+    let treasury_before = ctx.accounts.treasury.amount;
+    let page = process_investor_page(&ctx, &parsed, first_index, page_size, current_ts)?;
+    let creator_page_amount = if ctx.accounts.policy.creator_paid_per_page {
+        pay_creator_page_share(&ctx, page.investors_processed)?
+    } else {
+        0
+    };
+    ctx.accounts.treasury.reload()?;
+    
     // Update progress
-    progress.daily_distributed = MathUtil::safe_add(
-        progress.daily_distributed,
-        total_distributed
+    let (day_finished, day_id, event_seq) = {
+        let mut progress = ctx.accounts.progress.load_mut()?;
+        progress.daily_distributed = MathUtil::safe_add(
+            progress.daily_distributed,
+            page.total_distributed
+        )?;
+        progress.daily_dust = MathUtil::safe_add(progress.daily_dust, page.dust)?;
+        progress.daily_residue = MathUtil::safe_add(progress.daily_residue, page.residue)?;
+        progress.dust_ledger = MathUtil::safe_add(
+            progress.dust_ledger,
+            MathUtil::safe_sub(page.dust, page.investor_dust_credited)?
+        )?;
+        progress.investor_dust_owed = MathUtil::safe_sub(
+            MathUtil::safe_add(progress.investor_dust_owed, page.investor_dust_credited)?,
+            page.investor_dust_released
+        )?;
+        progress.creator_paid_today = MathUtil::safe_add(progress.creator_paid_today, creator_page_amount)?;
+        progress.record_treasury_outflow(
+            treasury_before,
+            ctx.accounts.treasury.amount,
+            MathUtil::safe_add(
+                MathUtil::safe_add(page.total_distributed, page.investor_dust_released)?,
+                creator_page_amount
+            )?,
+        )?;
+        
+        // Failed payouts are counted so monitoring can alert without parsing logs, and every
+        // skipped investor is reported so a missing payout can be explained
+        for entry in &page.skipped {
+            let error_code = entry.reason.failure().map_or(0, u32::from);
+            if error_code != 0 {
+                progress.failed_transfer_count = MathUtil::safe_add(progress.failed_transfer_count, 1)?;
+                progress.last_error_code = error_code;
+            }
+            emit!(InvestorSkipped {
+                vault,
+                day_id: progress.day_id,
+                event_seq: progress.next_event_seq(),
+                index: entry.index,
+                stream_account: entry.stream_account,
+                reason: entry.reason,
+                error_code,
+                timestamp: current_ts,
+            });
+        }
+        
+        // Every index of the page is marked, so a retried page pays nobody twice
+        for index in page.page_start..page.page_end {
+            progress.mark_processed(index)?;
+        }
+        (
+            progress.pagination_cursor == progress.expected_investor_count,
+            progress.day_id,
+            progress.next_event_seq(),
+        )
+    };
+    
+    let stats = &mut ctx.accounts.stats;
+    stats.total_paid_to_investors = MathUtil::safe_add(
+        stats.total_paid_to_investors,
+        page.total_distributed
     )?;
+    stats.total_dust = MathUtil::safe_add(stats.total_dust, page.dust)?;
     
     // Emit page event
     emit!(InvestorPayoutPage {
         vault,
-        page_start: progress.pagination_cursor,
-        page_end: progress.pagination_cursor + investors_processed,
-        total_distributed,
-        investor_count: investors_processed,
+        day_id,
+        event_seq,
+        page_start: page.page_start,
+        page_end: page.page_end,
+        total_distributed: page.total_distributed,
+        creator_distributed: creator_page_amount,
+        quote_decimals: ctx.accounts.policy.quote_decimals_for(&ctx.accounts.treasury.mint),
+        investor_count: page.investors_processed,
+        skipped: page.skipped,
         timestamp: current_ts,
     });
     
-    // Update cursor
-    progress.pagination_cursor = MathUtil::safe_add(
-        progress.pagination_cursor,
-        investors_processed
-    )?;
-    
-    // Check if this was the final page of the day
-    if progress.pagination_cursor >= ctx.remaining_accounts.len() as u64 {
+    // Check if every investor of the day has been processed
+    if day_finished {
+        // Registry days close only once the cursor covers every registered investor and snapshot entry
+        if ctx.accounts.policy.registry_enabled {
+            let (registry, snapshot) = match (&ctx.accounts.investor_registry, &ctx.accounts.locked_snapshot) {
+                (Some(registry), Some(snapshot)) => (registry, snapshot),
+                _ => return err!(HonoraryFeeError::RegistryAccountsRequired),
+            };
+            let registered = registry.investors.len().max(snapshot.locked.len()) as u64;
+            require!(
+                ctx.accounts.progress.load()?.pagination_cursor >= registered,
+                HonoraryFeeError::RegistryIncomplete
+            );
+        }
+        
         // Final page - distribute remainder to creator and close the day
-        close_day_and_pay_creator(ctx, current_ts)?;
+        let creator_only = parsed.total_locked == 0;
+        
+        // The investor entitlement for the whole day, before the daily cap
+        let investor_entitlement = {
+            let progress = ctx.accounts.progress.load()?;
+            investor_allocation(
+                &ctx.accounts.policy,
+                progress.investor_fee_share_bps,
+                progress.eligible_share_bps,
+                progress.total_available()?,
+                0,
+            )?
+        };
+        let capped_excess = capped_investor_excess(
+            ctx.accounts.progress.load()?.daily_cap,
+            investor_entitlement,
+        );
+        
+        close_day_and_pay_creator(
+            ctx.accounts.day_close(ctx.accounts.policy.position_owner_bump),
+            current_ts,
+            creator_only,
+            capped_excess,
+        )?;
     }
     
     Ok(())
 }
 
-/// Claim fees from the honorary position
-fn claim_fees_from_position(ctx: &Context<DistributeFees>) -> Result<()> {
+/// Pay the creator its day share pro rata to the investors processed so far
+///
+/// The creator's day share is what the day's investor entitlement leaves over before the
+/// daily cap, so it never exceeds the remainder paid at day close. Earlier pages' payments
+/// are subtracted, and day close pays whatever is left.
+fn pay_creator_page_share(
+    ctx: &Context<DistributeFees>,
+    investors_processed: u64,
+) -> Result<u64> {
+    let progress = ctx.accounts.progress.load()?;
+    let total_available = progress.total_available()?;
+    let investor_entitlement = investor_allocation(
+        &ctx.accounts.policy,
+        progress.investor_fee_share_bps,
+        progress.eligible_share_bps,
+        total_available,
+        0,
+    )?;
+    let creator_share = MathUtil::safe_sub(total_available, investor_entitlement)?;
+    let processed = MathUtil::safe_add(progress.processed_count, investors_processed)?
+        .min(progress.expected_investor_count);
+    let (creator_due, _remainder) = MathUtil::calculate_proportional_payout(
+        creator_share,
+        processed,
+        progress.expected_investor_count,
+    )?;
+    let amount = creator_due.saturating_sub(progress.creator_paid_today);
+    drop(progress);
+    
+    if amount == 0 {
+        return Ok(0);
+    }
+    
+    // A frozen creator ATA cannot receive the share; day close accrues it for a later claim
+    if ctx.accounts.creator_quote_ata.is_frozen() {
+        msg!("Creator ATA is frozen, holding {} for day close", amount);
+        return Ok(0);
+    }
+    
     let vault_key = ctx.accounts.vault.key();
     let seeds = &[
         VAULT_SEED,
         vault_key.as_ref(),
         INVESTOR_FEE_POS_OWNER_SEED,
-        &[ctx.bumps.position_owner_pda],
+        &[ctx.accounts.policy.position_owner_bump],
     ];
-    let _signer = &[&seeds[..]];
-    
-    // Get treasury balance before claim
-    let treasury_before = ctx.accounts.treasury.amount;
+    let signer = &[&seeds[..]];
     
-    // Make CPI call to cp-amm to claim fees
-    msg!("Claiming fees from honorary position");
-    
-    // Placeholder for actual cp-amm fee claiming CPI
-    // In real implementation, this would be:
-    /*
-    let cpi_accounts = cp_amm::cpi::accounts::ClaimFees {
-        position: ctx.accounts.position.to_account_info(),
-        position_authority: ctx.accounts.position_owner_pda.to_account_info(),
-        treasury_a: ctx.accounts.treasury.to_account_info(),
-        treasury_b: ctx.accounts.treasury.to_account_info(), // or another account
-        // ... other required accounts
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.treasury.to_account_info(),
+        to: ctx.accounts.creator_quote_ata.to_account_info(),
+        authority: ctx.accounts.position_owner_pda.to_account_info(),
     };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    token::transfer(cpi_ctx, amount)?;
     
-    let cpi_program = ctx.accounts.cp_amm_program.to_account_info();
-    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-    
-    let claim_result = cp_amm::cpi::claim_fees(cpi_ctx)?;
-    */
+    msg!("Paid {} to creator with the page", amount);
     
-    // For now, simulate claiming some fees
-    ctx.accounts.treasury.reload()?;
-    let treasury_after = ctx.accounts.treasury.amount;
-    let claimed_amount = treasury_after.saturating_sub(treasury_before);
-    
-    // Validate no base fees were claimed
-    // In real implementation, we'd check the claim result for base token amounts
-    let _claimed_tokens = vec![
-        (ctx.accounts.policy.quote_mint, claimed_amount),
-        // Would also include base mint with amount 0 in real implementation
+    Ok(amount)
+}
+
+/// Claim fees from the honorary position
+fn claim_fees_from_position(ctx: &mut Context<DistributeFees>, current_ts: i64) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let seeds = &[
+        VAULT_SEED,
+        vault_key.as_ref(),
+        INVESTOR_FEE_POS_OWNER_SEED,
+        &[ctx.accounts.policy.position_owner_bump],
     ];
+    let signer = &[&seeds[..]];
     
-    // Get base mint from pool (placeholder)
-    let base_mint = Pubkey::default(); // Would be extracted from pool
-    
-    PoolValidator::detect_base_fees_in_claim(
-        claimed_amount, // claim_amount_a (quote token)
-        0, // claim_amount_b (base token - should be 0)
+    // Claims only come from the vault's pool, which must be enabled and still accrue quote-only fees
+    PoolValidator::validate_pool_for_distribution(
+        &ctx.accounts.pool,
+        &ctx.accounts.cp_amm_program.key(),
+    )?;
+    PoolValidator::validate_quote_only_config(
+        &ctx.accounts.pool,
+        &ctx.accounts.cp_amm_program.key(),
         &ctx.accounts.policy.quote_mint,
-        &ctx.accounts.policy.quote_mint, // token_a_mint (quote)
-        &base_mint, // token_b_mint (base)
+        &ctx.accounts.policy.base_mint,
     )?;
     
-    // Update progress with claimed amount
-    ctx.accounts.progress.daily_claimed_total = claimed_amount;
+    // The claim metrics are measured across the CPI; base fees are claimed too and quarantined below
+    let pool = CpAmmPool::try_deserialize(&ctx.accounts.pool.try_borrow_data()?)?;
+    let (token_a_mint, token_b_mint) = (pool.token_a_mint, pool.token_b_mint);
+    let fees_before = PositionFees::try_from_bytes(&ctx.accounts.position.try_borrow_data()?)?;
+    
+    // Base fees the position owes show the pool started charging them in the base mint
+    if !ctx.accounts.policy.has_secondary_quote() {
+        let (_, base_pending) = fees_before
+            .quote_and_base_pending(&token_a_mint, &token_b_mint, &ctx.accounts.policy.quote_mint)
+            .ok_or(HonoraryFeeError::InvalidQuoteMint)?;
+        if base_pending > 0 {
+            msg!("Honorary position owes {} base token fees; claiming them into quarantine", base_pending);
+        }
+    }
+    
+    // A day claims once, whatever the timestamps say; a retried day start cannot count fees twice
+    require!(
+        !ctx.accounts.progress.load()?.is_claimed_this_day(),
+        HonoraryFeeError::DayAlreadyClaimed
+    );
+    
+    // Get treasury balances before claim
+    let treasury_before = ctx.accounts.treasury.amount;
+    let paired_before = ctx.accounts.paired_treasury.as_ref().map_or(0, |treasury| treasury.amount);
+    let base_before = ctx.accounts.base_treasury.as_ref().map_or(0, |account| account.amount);
+    
+    // Claim the position's fees from cp-amm, signed by the position owner PDA
+    msg!("Claiming fees from honorary position in pool {}", ctx.accounts.pool.key);
+    ctx.accounts.claim_position_fee(&pool)?.invoke_signed(signer)?;
     
-    // Emit claim event
+    // Claimed amounts come from the position's claim metrics; anything else the treasuries
+    // received during the claim is surplus and never counted as claimed fees
+    let fees_after = PositionFees::try_from_bytes(&ctx.accounts.position.try_borrow_data()?)?;
+    let (claimed_amount, other_claimed) = fees_after
+        .quote_and_base_claimed_since(&fees_before, &token_a_mint, &token_b_mint, &ctx.accounts.policy.quote_mint)
+        .ok_or(HonoraryFeeError::InvalidQuoteMint)?;
+    ctx.accounts.treasury.reload()?;
+    require_claim_received(treasury_before, ctx.accounts.treasury.amount, claimed_amount)?;
+    
+    // With a secondary quote mint, the pool's other mint is a quote asset too
+    let base_claimed = match &mut ctx.accounts.base_treasury {
+        Some(base_treasury) if !ctx.accounts.policy.has_secondary_quote() => {
+            base_treasury.reload()?;
+            require_claim_received(base_before, base_treasury.amount, other_claimed)?;
+            other_claimed
+        }
+        _ => 0,
+    };
+    
+    // Update progress with claimed amount, on top of any claimed earlier by the other mint's crank
+    let (day_id, event_seq, quarantine) = {
+        let mut progress = ctx.accounts.progress.load_mut()?;
+        let quarantine = progress.record_claim(claimed_amount, base_claimed)?;
+        (progress.day_id, progress.next_event_seq(), quarantine)
+    };
+    
+    // Emit claim event before the base-fee check so a quarantined claim logs its amounts
     emit!(QuoteFeesClaimed {
         vault: ctx.accounts.vault.key(),
+        day_id,
+        event_seq,
         amount_claimed: claimed_amount,
-        quote_mint: ctx.accounts.policy.quote_mint,
-        timestamp: ctx.accounts.clock.unix_timestamp,
+        base_amount_claimed: base_claimed,
+        quote_mint: ctx.accounts.treasury.mint,
+        quote_decimals: ctx.accounts.policy.quote_decimals_for(&ctx.accounts.treasury.mint),
+        timestamp: current_ts,
     });
     
+    // Base fees the claim returned stay in the base treasury and the vault pauses; failing
+    // instead would leave them in the position and block every later day start
+    if quarantine {
+        emit_base_fees_detected(ctx, base_claimed, current_ts)?;
+        let reason = PauseReason::BaseFeesDetected;
+        ctx.accounts.policy.pause_reason = Some(reason);
+        emit!(VaultPaused {
+            vault: ctx.accounts.vault.key(),
+            day_id,
+            event_seq: ctx.accounts.progress.load_mut()?.next_event_seq(),
+            reason,
+            paused_by: ctx.accounts.payer.key(),
+            timestamp: current_ts,
+        });
+        msg!("Quarantined {} base token fees; distributions paused", base_claimed);
+    }
+    
+    // Fees claimed in the other quote mint wait for that mint's next day start
+    if let (Some(paired_treasury), Some(paired_progress)) =
+        (&mut ctx.accounts.paired_treasury, &ctx.accounts.paired_progress)
+    {
+        paired_treasury.reload()?;
+        let paired_claimed = other_claimed;
+        require_claim_received(paired_before, paired_treasury.amount, paired_claimed)?;
+        let mut paired = paired_progress.load_mut()?;
+        paired.pending_claimed = MathUtil::safe_add(paired.pending_claimed, paired_claimed)?;
+        paired.treasury_checkpoint = MathUtil::safe_add(paired.treasury_checkpoint, paired_claimed)?;
+        
+        emit!(QuoteFeesClaimed {
+            vault: ctx.accounts.vault.key(),
+            day_id,
+            event_seq: paired.next_event_seq(),
+            amount_claimed: paired_claimed,
+            base_amount_claimed: 0,
+            quote_mint: paired_treasury.mint,
+            quote_decimals: ctx.accounts.policy.quote_decimals_for(&paired_treasury.mint),
+            timestamp: current_ts,
+        });
+    }
+    
     Ok(())
 }
 
-/// Process a page of investor distributions
-fn process_investor_page(
+/// Alert monitoring that the claim returned base-token fees into the base treasury
+fn emit_base_fees_detected(
     ctx: &Context<DistributeFees>,
-    page_size: u32,
-) -> Result<(u64, u32)> {
-    let policy = &ctx.accounts.policy;
-    let progress = &ctx.accounts.progress;
-    let cursor = progress.pagination_cursor as usize;
-    
-    // Calculate available amount for this page
-    let total_available = MathUtil::safe_add(
-        progress.daily_claimed_total,
-        progress.carry_over
-    )?;
-    let already_distributed = progress.daily_distributed;
-    let remaining_for_distribution = MathUtil::safe_sub(total_available, already_distributed)?;
+    base_amount: u64,
+    current_ts: i64,
+) -> Result<()> {
+    let mut progress = ctx.accounts.progress.load_mut()?;
+    emit!(BaseFeesDetected {
+        vault: ctx.accounts.vault.key(),
+        day_id: progress.day_id,
+        event_seq: progress.next_event_seq(),
+        pool: ctx.accounts.pool.key(),
+        position: ctx.accounts.position.key(),
+        base_mint: ctx.accounts.policy.base_mint,
+        base_amount,
+        timestamp: current_ts,
+    });
+    Ok(())
+}
+
+/// Where investor locked amounts come from
+#[derive(Clone, Copy)]
+pub(crate) enum LockedSource<'a> {
+    /// Read live from each stream account, which must be owned by an allowed locker program,
+    /// stream the vault's allocation (base) mint and come from the vault's stream sender
+    Streams {
+        global_config: &'a GlobalConfig,
+        allocation_mint: Pubkey,
+        stream_sender: Pubkey,
+    },
+    /// Registry entries `[first_index, page_end)` with the snapshot taken before the day started
+    Snapshot {
+        registry: &'a InvestorRegistry,
+        snapshot: &'a LockedSnapshot,
+        first_index: u64,
+        page_end: u64,
+    },
+    /// Page entries proven against the day's committed investor set
+    Merkle {
+        investor_set: InvestorSetCommitment,
+        first_index: u64,
+        proofs: &'a [InvestorProof],
+    },
+}
+
+/// Pick the locked-amount source configured by the policy for the page `[page.start, page.end)`
+pub(crate) fn locked_source<'a>(
+    global_config: &'a GlobalConfig,
+    policy: &PolicyState,
+    investor_set: Option<InvestorSetCommitment>,
+    page: Range<u64>,
+    proofs: &'a [InvestorProof],
+    investor_registry: &'a Option<Account<InvestorRegistry>>,
+    locked_snapshot: &'a Option<Account<LockedSnapshot>>,
+) -> Result<LockedSource<'a>> {
+    if policy.merkle_investor_set {
+        let investor_set = investor_set.ok_or(HonoraryFeeError::InvalidInvestorSetCommitment)?;
+        return Ok(LockedSource::Merkle { investor_set, first_index: page.start, proofs });
+    }
     
-    // Get investor data from remaining accounts
-    let investor_accounts = parse_investor_accounts(&ctx.remaining_accounts[cursor..])?;
-    let page_end = (cursor + page_size as usize).min(investor_accounts.len());
-    let investors_this_page = &investor_accounts[..page_end.saturating_sub(cursor)];
+    if !policy.registry_enabled {
+        return Ok(LockedSource::Streams {
+            global_config,
+            allocation_mint: policy.base_mint,
+            stream_sender: policy.stream_sender,
+        });
+    }
     
-    // Calculate total locked amount for this page
-    let total_locked_this_page: u64 = investors_this_page
+    match (investor_registry, locked_snapshot) {
+        (Some(registry), Some(snapshot)) => {
+            Ok(LockedSource::Snapshot {
+                registry,
+                snapshot,
+                first_index: page.start,
+                page_end: page.end.min(registry.investors.len() as u64),
+            })
+        }
+        _ => err!(HonoraryFeeError::RegistryAccountsRequired),
+    }
+}
+
+/// Payout decided for one investor of a page
+pub struct PlannedPayout<'a, 'info> {
+    pub investor: &'a InvestorDistributionAccount<'info>,
+    pub payout: u64,
+    /// Below the minimum payout threshold; withheld in the dust ledger instead of transferred
+    pub is_dust: bool,
+}
+
+/// The day's amounts a page is planned against
+#[derive(Clone, Copy)]
+pub struct DayBudget {
+    /// Paid to investors so far today
+    pub already_distributed: u64,
+    /// Still undistributed today
+    pub remaining_for_distribution: u64,
+    /// Investor fee share fixed at day start
+    pub investor_fee_share_bps: u16,
+    /// Locked-weighted share fixed by the day's first page
+    pub eligible_share_bps: u16,
+    /// Daily investor cap fixed at day start (0 = no cap)
+    pub daily_cap: u64,
+    /// Minimum payout fixed by the day's first page
+    pub min_payout: u64,
+}
+
+/// Payout math for one investor page, computed without moving any tokens
+pub struct PagePlan<'a, 'info> {
+    pub page_start: u64,
+    pub page_end: u64,
+    /// The day's investor allocation after the eligible share and daily cap
+    pub investor_total: u64,
+    /// Smallest payout transferred today; smaller ones are withheld as dust
+    pub min_payout: u64,
+    pub payouts: Vec<PlannedPayout<'a, 'info>>,
+    pub skipped: Vec<SkippedInvestorEntry>,
+    /// Units of the paid investors' share lost to flooring (0 under `LargestRemainder`)
+    pub residue: u64,
+}
+
+/// Share of the day's fees weighted by the locked fraction under the policy's order, in basis points
+pub(crate) fn eligible_share_bps(
+    policy: &PolicyState,
+    total_locked: u64,
+    investor_fee_share_bps: u16,
+) -> Result<u16> {
+    policy.distribution_order.eligible_share_bps(
+        total_locked,
+        policy.total_investor_allocation,
+        investor_fee_share_bps,
+    )
+}
+
+/// Investor allocation still available today under the policy's order, before the daily cap
+pub(crate) fn investor_allocation(
+    policy: &PolicyState,
+    investor_fee_share_bps: u16,
+    eligible_share_bps: u16,
+    total_available: u64,
+    already_distributed: u64,
+) -> Result<u64> {
+    policy.distribution_order.investor_allocation(
+        investor_fee_share_bps,
+        eligible_share_bps,
+        total_available,
+        already_distributed,
+    )
+}
+
+/// Smallest payout transferred on a day with `total_available` to distribute
+///
+/// In `AllocationBps` mode the threshold is a share of the day's whole investor
+/// allocation after the daily cap, so it is the same for every page of the day.
+pub(crate) fn min_payout_threshold(
+    policy: &PolicyState,
+    daily_cap: u64,
+    investor_fee_share_bps: u16,
+    eligible_share_bps: u16,
+    total_available: u64,
+) -> Result<u64> {
+    match policy.min_payout_mode {
+        MinPayoutMode::Fixed => Ok(policy.min_payout_lamports),
+        MinPayoutMode::AllocationBps => {
+            let day_allocation = investor_allocation(
+                policy,
+                investor_fee_share_bps,
+                eligible_share_bps,
+                total_available,
+                0,
+            )?;
+            let capped_allocation = if daily_cap > 0 {
+                day_allocation.min(daily_cap)
+            } else {
+                day_allocation
+            };
+            MathUtil::apply_bps(capped_allocation, policy.min_payout_bps as u64)
+        }
+    }
+}
+
+/// An investor's floored share of the day's allocation, taken out of what is left of it
+///
+/// `day_allocation` and `total_locked_today` are fixed for the day, so an investor's share
+/// does not depend on which page it is paid with.
+pub fn investor_day_payout(
+    day_allocation: u64,
+    weight: u64,
+    total_locked_today: u64,
+    remaining_allocation: &mut u64,
+) -> Result<u64> {
+    let (payout, _remainder) = MathUtil::calculate_proportional_payout(day_allocation, weight, total_locked_today)?;
+    let payout = payout.min(*remaining_allocation);
+    *remaining_allocation -= payout;
+    Ok(payout)
+}
+
+/// Compute the payouts for the investors in `[page_start, page_start + page_size)`
+///
+/// Investors for which `is_processed` holds were handled by an earlier page today
+/// and are left out, so replayed or overlapping pages never pay twice.
+pub(crate) fn plan_investor_page<'a, 'info>(
+    policy: &PolicyState,
+    parsed: &'a ParsedInvestors<'info>,
+    page_start: u64,
+    page_size: u32,
+    budget: DayBudget,
+    is_processed: impl Fn(u64) -> bool,
+) -> Result<PagePlan<'a, 'info>> {
+    let DayBudget {
+        already_distributed,
+        remaining_for_distribution,
+        investor_fee_share_bps,
+        eligible_share_bps,
+        daily_cap,
+        min_payout,
+    } = budget;
+    let total_available = MathUtil::safe_add(remaining_for_distribution, already_distributed)?;
+    let page_end = MathUtil::safe_add(page_start, page_size as u64)?.min(parsed.investor_count);
+    let page_start = page_start.min(page_end);
+    let in_page = |index: u64| index >= page_start && index < page_end && !is_processed(index);
+    
+    // Select the unprocessed investors and skipped entries that fall within this page
+    let investors_this_page: Vec<&InvestorDistributionAccount> = parsed.investors
+        .iter()
+        .filter(|inv| in_page(inv.index))
+        .collect();
+    let mut skipped: Vec<SkippedInvestorEntry> = parsed.skipped
         .iter()
-        .map(|inv| inv.locked_amount)
-        .sum();
+        .filter(|entry| in_page(entry.index))
+        .cloned()
+        .collect();
     
-    if total_locked_this_page == 0 {
-        return Ok((0, investors_this_page.len() as u32));
+    // Calculate total weight for this page
+    let mut total_locked_this_page = 0u64;
+    for investor in &investors_this_page {
+        total_locked_this_page = MathUtil::safe_add(total_locked_this_page, investor.weight()?)?;
     }
     
-    // Calculate investor share based on locked percentage
-    let total_locked_all = get_total_locked_amount(&investor_accounts)?;
-    let eligible_share_bps = MathUtil::calculate_eligible_share_bps(
-        total_locked_all,
-        policy.total_investor_allocation,
-        policy.investor_fee_share_bps,
-    )?;
+    // Every page divides the allocation fixed at day start by the locked total fixed by the first page
+    let total_locked_today = parsed.total_locked;
+    if total_locked_this_page == 0 || total_locked_today == 0 {
+        skipped.extend(investors_this_page.iter().filter_map(|inv| skipped_entry(inv)));
+        skipped.sort_by_key(|entry| entry.index);
+        return Ok(PagePlan {
+            page_start,
+            page_end,
+            investor_total: 0,
+            min_payout,
+            payouts: Vec::new(),
+            skipped,
+            residue: 0,
+        });
+    }
     
-    // Calculate total investor allocation for this distribution
-    let investor_total = MathUtil::safe_div(
-        MathUtil::safe_mul(remaining_for_distribution, eligible_share_bps as u64)?,
-        10000
+    // Calculate the day's total investor allocation, independent of what earlier pages paid
+    let investor_total = investor_allocation(
+        policy,
+        investor_fee_share_bps,
+        eligible_share_bps,
+        total_available,
+        0,
     )?;
     
     // Apply daily cap if configured
-    let capped_investor_total = if policy.daily_cap > 0 {
-        investor_total.min(policy.daily_cap.saturating_sub(already_distributed))
+    let capped_investor_total = if daily_cap > 0 {
+        investor_total.min(daily_cap)
     } else {
         investor_total
     };
     
-    // Distribute to investors in this page
-    let mut total_page_distribution = 0u64;
+    // Streams topped up since the first page cannot push the day past its allocation
+    let mut page_budget = capped_investor_total.saturating_sub(already_distributed);
     
+    let mut shares = Vec::with_capacity(investors_this_page.len());
+    let mut paid_weight = 0u64;
     for investor in investors_this_page {
-        let (payout, _remainder) = MathUtil::calculate_proportional_payout(
+        // Investors with an invalid ATA keep their weight so their share is not
+        // redistributed to the rest of the page; it stays in the day's remainder
+        if let Some(entry) = skipped_entry(investor) {
+            skipped.push(entry);
+            continue;
+        }
+        
+        let weight = investor.weight()?;
+        let payout = investor_day_payout(capped_investor_total, weight, total_locked_today, &mut page_budget)?;
+        let fraction = MathUtil::calculate_proportional_remainder(
             capped_investor_total,
-            investor.locked_amount,
-            total_locked_this_page,
+            weight,
+            total_locked_today,
         )?;
+        paid_weight = MathUtil::safe_add(paid_weight, weight)?;
+        shares.push((investor, payout, fraction, weight));
+    }
+    
+    let (paid_total, _remainder) = MathUtil::calculate_proportional_payout(
+        capped_investor_total,
+        paid_weight,
+        total_locked_today,
+    )?;
+    let floored_total = shares.iter().map(|(_, payout, _, _)| *payout).sum::<u64>();
+    let mut residue = paid_total.saturating_sub(floored_total).min(page_budget);
+    
+    if policy.rounding_mode == RoundingMode::LargestRemainder {
+        // Units lost to flooring go to the largest fractional remainders, ties to the larger weight
+        let mut order: Vec<usize> = (0..shares.len()).collect();
+        order.sort_by(|&a, &b| {
+            shares[b].2.cmp(&shares[a].2)
+                .then(shares[b].3.cmp(&shares[a].3))
+                .then(shares[a].0.index.cmp(&shares[b].0.index))
+        });
+        for &position in order.iter().take(residue as usize) {
+            shares[position].1 = MathUtil::safe_add(shares[position].1, 1)?;
+        }
+        residue = 0;
+    }
+    
+    let payouts = shares
+        .into_iter()
+        .map(|(investor, payout, _fraction, _weight)| PlannedPayout {
+            investor,
+            payout,
+            is_dust: payout < min_payout,
+        })
+        .collect();
+    
+    skipped.sort_by_key(|entry| entry.index);
+    
+    Ok(PagePlan {
+        page_start,
+        page_end,
+        investor_total: capped_investor_total,
+        min_payout,
+        payouts,
+        skipped,
+        residue,
+    })
+}
+
+/// Process a page of investor distributions
+fn process_investor_page<'info>(
+    ctx: &Context<'_, '_, '_, 'info, DistributeFees<'info>>,
+    parsed: &ParsedInvestors<'info>,
+    page_start: u64,
+    page_size: u32,
+    current_ts: i64,
+) -> Result<PageResult> {
+    let progress = ctx.accounts.progress.load()?;
+    let plan = plan_investor_page(
+        &ctx.accounts.policy,
+        parsed,
+        page_start,
+        page_size,
+        DayBudget {
+            already_distributed: progress.daily_distributed,
+            remaining_for_distribution: progress.remaining_for_distribution()?,
+            investor_fee_share_bps: progress.investor_fee_share_bps,
+            eligible_share_bps: progress.eligible_share_bps,
+            daily_cap: progress.daily_cap,
+            min_payout: progress.min_payout,
+        },
+        |index| progress.is_processed(index),
+    )?;
+    let investors_processed = (plan.page_start..plan.page_end)
+        .filter(|index| !progress.is_processed(*index))
+        .count() as u64;
+    let day_id = progress.day_id;
+    drop(progress);
+    let mut skipped = plan.skipped;
+    
+    // Distribute to investors in this page
+    let mut total_page_distribution = 0u64;
+    let mut page_dust = 0u64;
+    let mut investor_dust_credited = 0u64;
+    let mut investor_dust_released = 0u64;
+    
+    for planned in &plan.payouts {
+        let investor = planned.investor;
         
-        // Apply minimum payout threshold
-        if payout >= policy.min_payout_lamports {
+        // A stream already paid today keeps its share in the day's remainder
+        if let Some(receipt) = &investor.payout_receipt {
+            if !create_payout_receipt(ctx, investor, receipt, day_id)? {
+                msg!("Skipping investor {}: stream {} already paid today", investor.index, investor.stream_account);
+                skipped.push(SkippedInvestorEntry {
+                    index: investor.index,
+                    stream_account: investor.stream_account,
+                    reason: SkipReason::AlreadyPaidToday,
+                });
+                continue;
+            }
+        }
+        
+        let mut record = match &investor.payout_record {
+            Some(payout_record) => read_payout_record(ctx, investor, payout_record)?,
+            None => None,
+        };
+        
+        // Investors with a record get their earlier dust added to today's payout
+        let carried_dust = record.as_ref().map_or(0, |record| record.pending_dust);
+        let amount = MathUtil::safe_add(planned.payout, carried_dust)?;
+        
+        // A frozen ATA would revert the page; the payout is escrowed in the investor's record,
+        // or without one skipped so it stays in the day's remainder
+        let ata_frozen = is_frozen_token_account(&investor.investor_quote_ata);
+        if ata_frozen {
+            msg!("Skipping investor {}: quote ATA {} is frozen", investor.index, investor.investor_quote_ata.key);
+            skipped.push(SkippedInvestorEntry {
+                index: investor.index,
+                stream_account: investor.stream_account,
+                reason: SkipReason::FrozenInvestorAta,
+            });
+            if record.is_none() {
+                continue;
+            }
+        }
+        
+        if ata_frozen || amount < plan.min_payout {
+            if !ata_frozen {
+                skipped.push(SkippedInvestorEntry {
+                    index: investor.index,
+                    stream_account: investor.stream_account,
+                    reason: SkipReason::BelowMinPayout,
+                });
+            }
+            
+            // Withhold sub-threshold payouts and payouts to frozen ATAs; investors with a record keep them for later
+            page_dust = MathUtil::safe_add(page_dust, planned.payout)?;
+            if let Some(record) = record.as_mut() {
+                record.pending_dust = amount;
+                investor_dust_credited = MathUtil::safe_add(investor_dust_credited, planned.payout)?;
+            }
+        } else {
             // Transfer tokens to investor
-            transfer_to_investor(&ctx, investor, payout)?;
-            total_page_distribution = MathUtil::safe_add(total_page_distribution, payout)?;
+            transfer_to_investor(ctx, investor, amount)?;
+            total_page_distribution = MathUtil::safe_add(total_page_distribution, planned.payout)?;
+            investor_dust_released = MathUtil::safe_add(investor_dust_released, carried_dust)?;
+            if let Some(record) = record.as_mut() {
+                record.pending_dust = 0;
+                record.total_received = MathUtil::safe_add(record.total_received, amount)?;
+                record.payout_count = MathUtil::safe_add(record.payout_count, 1)?;
+                record.last_payout_ts = current_ts;
+            }
+        }
+        
+        if let (Some(record), Some(payout_record)) = (record, &investor.payout_record) {
+            record.try_serialize(&mut &mut payout_record.try_borrow_mut_data()?[..])?;
         }
     }
     
-    Ok((total_page_distribution, investors_this_page.len() as u32))
+    skipped.sort_by_key(|entry| entry.index);
+    
+    Ok(PageResult {
+        page_start: plan.page_start,
+        page_end: plan.page_end,
+        total_distributed: total_page_distribution,
+        dust: page_dust,
+        investor_dust_credited,
+        investor_dust_released,
+        residue: plan.residue,
+        investors_processed,
+        skipped,
+    })
+}
+
+/// Build the skip record for an investor whose ATA failed validation
+fn skipped_entry(investor: &InvestorDistributionAccount) -> Option<SkippedInvestorEntry> {
+    investor.skip_reason.map(|reason| SkippedInvestorEntry {
+        index: investor.index,
+        stream_account: investor.stream_account,
+        reason,
+    })
 }
 
 /// Parse investor account data from remaining accounts
-fn parse_investor_accounts(
-    remaining_accounts: &[AccountInfo]
-) -> Result<Vec<InvestorDistributionAccount>> {
+///
+/// Malformed entries do not abort the crank: they are recorded with a reason code
+/// and excluded from the payout so the rest of the page can still be paid.
+///
+/// With a registry, the accounts cover exactly the page's registry entries, in registry
+/// order, and locked amounts come from the day's snapshot. In Merkle mode, the accounts
+/// cover only the current page and every entry must be proven against the committed
+/// investor set.
+pub(crate) fn parse_investor_accounts<'info>(
+    remaining_accounts: &[AccountInfo<'info>],
+    quote_mint: &Pubkey,
+    create_missing_atas: bool,
+    payout_records: bool,
+    payout_receipts: bool,
+    source: LockedSource,
+) -> Result<ParsedInvestors<'info>> {
     let mut investors = Vec::new();
+    let mut skipped = Vec::new();
+    let mut page_streams: Vec<Pubkey> = Vec::new();
     
-    // Each investor needs 2 accounts: stream + ATA
-    for chunk in remaining_accounts.chunks(2) {
-        if chunk.len() < 2 {
-            break;
-        }
-        
+    let (first_index, proofs) = match source {
+        LockedSource::Merkle { first_index, proofs, .. } => (first_index, Some(proofs)),
+        LockedSource::Snapshot { first_index, .. } => (first_index, None),
+        LockedSource::Streams { .. } => (0, None),
+    };
+    let mut index = first_index;
+    
+    // Each investor needs 2 accounts: stream + ATA, plus the ATA owner wallet
+    // when missing ATAs are created and the payout receipt and record slots when enabled
+    let receipt_slot = 2 + create_missing_atas as usize;
+    let accounts_per_investor = receipt_slot + payout_receipts as usize + payout_records as usize;
+    if let Some(proofs) = proofs {
+        require!(
+            remaining_accounts.len() == proofs.len() * accounts_per_investor,
+            HonoraryFeeError::InvalidMerkleProof
+        );
+    }
+    
+    // Registry pages list exactly their own entries so keepers cannot reorder, omit or substitute investors
+    if let LockedSource::Snapshot { first_index, page_end, .. } = source {
+        let page_len = page_end.saturating_sub(first_index) as usize;
+        require!(
+            remaining_accounts.len() == page_len * accounts_per_investor,
+            HonoraryFeeError::NonCanonicalPage
+        );
+    }
+    
+    ValidationUtil::validate_investor_accounts(remaining_accounts, accounts_per_investor)?;
+    
+    for (position, chunk) in remaining_accounts.chunks(accounts_per_investor).enumerate() {
         let stream_account = chunk[0].key();
-        let investor_quote_ata = chunk[1].key();
         
-        // Read locked amount from Streamflow stream
-        let locked_amount = read_locked_amount_from_stream(&chunk[0])?;
+        // A stream listed twice in one page would be paid twice
+        require!(
+            !page_streams.contains(&stream_account),
+            HonoraryFeeError::DuplicateInvestor
+        );
+        page_streams.push(stream_account);
+        
+        // Read locked amount from the day's commitment or the Streamflow stream
+        let mut multiplier_bps = BASE_MULTIPLIER_BPS;
+        let mut recipient = None;
+        let locked_amount = match source {
+            LockedSource::Streams { global_config, allocation_mint, stream_sender } => {
+                let stream = global_config
+                    .is_allowed_locker_program(chunk[0].owner)
+                    .then(|| read_stream(&chunk[0]).ok())
+                    .flatten();
+                match stream {
+                    Some((_, contract)) if contract.mint != allocation_mint => {
+                        Err(SkipReason::StreamMintMismatch)
+                    }
+                    Some((_, contract))
+                        if stream_sender != Pubkey::default() && contract.sender != stream_sender =>
+                    {
+                        Err(SkipReason::ForeignStream)
+                    }
+                    Some((locked_amount, contract)) => {
+                        recipient = Some(contract.recipient);
+                        Ok(locked_amount)
+                    }
+                    None => Err(SkipReason::InvalidStreamAccount),
+                }
+            }
+            LockedSource::Snapshot { registry, snapshot, .. } => {
+                let registered = registry.investors
+                    .get(index as usize)
+                    .ok_or(HonoraryFeeError::InvestorNotRegistered)?;
+                require!(
+                    stream_account == registered.stream && chunk[1].key() == registered.quote_ata,
+                    HonoraryFeeError::InvestorNotRegistered
+                );
+                multiplier_bps = registered.multiplier_bps;
+                Ok(snapshot.locked[index as usize])
+            }
+            LockedSource::Merkle { investor_set, proofs, .. } => {
+                let entry = &proofs[position];
+                let leaf = MerkleUtil::investor_leaf(
+                    index,
+                    &stream_account,
+                    chunk[1].key,
+                    entry.locked_amount,
+                );
+                require!(
+                    index < investor_set.investor_count
+                        && MerkleUtil::verify(&entry.proof, &investor_set.root, leaf),
+                    HonoraryFeeError::InvalidMerkleProof
+                );
+                Ok(entry.locked_amount)
+            }
+        };
+        let locked_amount = match locked_amount {
+            Ok(locked_amount) => locked_amount,
+            Err(reason) => {
+                msg!("Skipping investor {}: stream account {} rejected ({:?})", index, stream_account, reason);
+                skipped.push(SkippedInvestorEntry {
+                    index,
+                    stream_account,
+                    reason,
+                });
+                index = MathUtil::safe_add(index, 1)?;
+                continue;
+            }
+        };
+        
+        let ata_missing = is_uninitialized_account(&chunk[1]);
+        let create_ata = create_missing_atas && ata_missing && chunk[1].is_writable;
+        let skip_reason = if create_ata && !is_recipient(recipient.as_ref(), chunk[2].key) {
+            msg!("Skipping investor {}: ATA owner {} is not the stream recipient", index, chunk[2].key);
+            Some(SkipReason::InvalidInvestorAta)
+        } else if create_ata || is_valid_token_account(&chunk[1], quote_mint, recipient.as_ref()) {
+            None
+        } else if ata_missing {
+            msg!("Skipping investor {}: quote ATA {} does not exist", index, chunk[1].key);
+            Some(SkipReason::MissingInvestorAta)
+        } else {
+            msg!("Skipping investor {}: invalid quote ATA {}", index, chunk[1].key);
+            Some(SkipReason::InvalidInvestorAta)
+        };
         
         investors.push(InvestorDistributionAccount {
+            index,
             stream_account,
-            investor_quote_ata,
+            investor_quote_ata: chunk[1].clone(),
+            investor_owner: create_missing_atas.then(|| chunk[2].clone()),
+            payout_record: payout_records.then(|| chunk[accounts_per_investor - 1].clone()),
+            payout_receipt: payout_receipts.then(|| chunk[receipt_slot].clone()),
+            recipient,
+            create_ata: create_ata && skip_reason.is_none(),
             locked_amount,
+            multiplier_bps,
+            skip_reason,
         });
+        index = MathUtil::safe_add(index, 1)?;
     }
     
-    Ok(investors)
+    let (investor_count, total_locked) = match source {
+        LockedSource::Streams { .. } => (index, get_total_locked_amount(&investors)?),
+        LockedSource::Snapshot { registry, snapshot, .. } => {
+            (registry.investors.len() as u64, snapshot.total_locked)
+        }
+        LockedSource::Merkle { investor_set, .. } => {
+            (investor_set.investor_count, investor_set.total_locked)
+        }
+    };
+    
+    Ok(ParsedInvestors {
+        investors,
+        skipped,
+        investor_count,
+        total_locked,
+    })
 }
 
-/// Read locked amount from a Streamflow stream account
-fn read_locked_amount_from_stream(stream_account: &AccountInfo) -> Result<u64> {
-    // Parse Streamflow stream account to get remaining locked tokens
-    // Streamflow streams have a specific account layout that we need to parse
-
-    require!(
-        stream_account.data_len() >= 200, // Minimum Streamflow stream size
-        HonoraryFeeError::InvalidStreamAccount
-    );
+/// Read the token balance of a token account
+fn token_balance(account: &AccountInfo) -> Result<u64> {
+    Ok(TokenAccount::try_deserialize(&mut &account.try_borrow_data()?[..])?.amount)
+}
 
-    let stream_data = stream_account.try_borrow_data()?;
+/// Check whether an account has never been created (no data, system-owned)
+fn is_uninitialized_account(account: &AccountInfo) -> bool {
+    account.data_is_empty() && account.owner == &anchor_lang::system_program::ID
+}
 
-    // Streamflow stream structure (approximate - needs verification):
-    // - discriminator: 8 bytes
-    // - id: 32 bytes (stream ID)
-    // - start_time: 8 bytes
-    // - end_time: 8 bytes
-    // - amount: 8 bytes (total amount)
-    // - withdrawn: 8 bytes (amount already withdrawn)
-    // - canceled_at: 8 bytes (optional)
-    // - recipient: 32 bytes
-    // - sender: 32 bytes
-    // - mint: 32 bytes
-    // - escrow: 32 bytes
-    // - streamflow_treasury: 32 bytes
-    // - streamflow_fee_treasury: 32 bytes
-    // - partner_fee_treasury: 32 bytes (optional)
-    // etc.
-
-    // For vesting streams, the locked amount is typically:
-    // locked = total_amount - withdrawn - already_released_based_on_time
-
-    // Extract basic fields
-    let total_amount = extract_u64_from_stream_data(&stream_data, 8 + 32 + 8 + 8)?; // offset after discriminator + id + start + end
-    let withdrawn = extract_u64_from_stream_data(&stream_data, 8 + 32 + 8 + 8 + 8)?; // + amount
-
-    // For time-based vesting, we need to calculate how much should be unlocked by now
-    let start_time = extract_u64_from_stream_data(&stream_data, 8 + 32)?; // after discriminator + id
-    let end_time = extract_u64_from_stream_data(&stream_data, 8 + 32 + 8)?;
-    let current_time = Clock::get()?.unix_timestamp as u64;
-
-    // Calculate unlocked amount based on time elapsed
-    let unlocked_amount = if current_time >= end_time {
-        total_amount // Fully unlocked
-    } else if current_time <= start_time {
-        0 // Not started yet
-    } else {
-        // Linear vesting calculation
-        let elapsed = current_time - start_time;
-        let total_duration = end_time - start_time;
-        ((total_amount as u128 * elapsed as u128) / total_duration as u128) as u64
-    };
+/// Check whether a token account is frozen (unparseable accounts are left to fail the transfer)
+fn is_frozen_token_account(account: &AccountInfo) -> bool {
+    match account.try_borrow_data() {
+        Ok(data) => TokenAccount::try_deserialize(&mut &data[..]).is_ok_and(|token_account| token_account.is_frozen()),
+        Err(_) => false,
+    }
+}
 
-    // Locked amount = total - max(withdrawn, unlocked_amount)
-    // This ensures we don't over-distribute if someone withdrew early
-    let already_released = withdrawn.max(unlocked_amount);
-    let locked_amount = total_amount.saturating_sub(already_released);
+/// Check that an account is an SPL token account for the quote mint that can receive a transfer
+///
+/// When the stream recipient is known, the token account must also be owned by it.
+fn is_valid_token_account(account: &AccountInfo, quote_mint: &Pubkey, recipient: Option<&Pubkey>) -> bool {
+    if account.owner != &token::ID || !account.is_writable {
+        return false;
+    }
+    
+    match account.try_borrow_data() {
+        Ok(data) => TokenAccount::try_deserialize(&mut &data[..]).is_ok_and(|token_account| {
+            token_account.mint == *quote_mint && is_recipient(recipient, &token_account.owner)
+        }),
+        Err(_) => false,
+    }
+}
 
-    msg!("Stream {}: total={}, withdrawn={}, unlocked={}, locked={}",
-         stream_account.key, total_amount, withdrawn, unlocked_amount, locked_amount);
+/// Check a wallet against the stream recipient (any wallet passes when the recipient is unknown)
+fn is_recipient(recipient: Option<&Pubkey>, wallet: &Pubkey) -> bool {
+    recipient.is_none() || recipient == Some(wallet)
+}
 
-    Ok(locked_amount)
+/// Read locked amount from a Streamflow stream account
+pub(crate) fn read_locked_amount_from_stream(stream_account: &AccountInfo) -> Result<u64> {
+    read_stream(stream_account).map(|(locked_amount, _)| locked_amount)
 }
 
-/// Helper to extract u64 from stream data at offset
-fn extract_u64_from_stream_data(data: &[u8], offset: usize) -> Result<u64> {
-    if offset + 8 > data.len() {
-        return Err(error!(HonoraryFeeError::InvalidStreamAccount));
-    }
+/// Read the locked amount and the parsed contract from a Streamflow stream account
+fn read_stream(stream_account: &AccountInfo) -> Result<(u64, StreamContract)> {
+    // Parse Streamflow stream account to get remaining locked tokens
+    let stream_data = stream_account.try_borrow_data()?;
+    let contract = StreamContract::try_from_bytes(&stream_data)?;
+
+    // Derive the locked amount from the vesting schedule at the current time
+    let current_time = Clock::get()?.unix_timestamp.max(0) as u64;
+    let vested_amount = contract.vested_amount_at(current_time);
+    let locked_amount = contract.locked_amount_at(current_time);
+
+    msg!("Stream {}: total={}, withdrawn={}, vested={}, locked={}",
+         stream_account.key, contract.net_amount_deposited, contract.amount_withdrawn, vested_amount, locked_amount);
 
-    let bytes = &data[offset..offset + 8];
-    Ok(u64::from_le_bytes(bytes.try_into().unwrap()))
+    Ok((locked_amount, contract))
 }
 
 /// Get total locked amount across all investors
-fn get_total_locked_amount(investors: &[InvestorDistributionAccount]) -> Result<u64> {
+pub(crate) fn get_total_locked_amount(investors: &[InvestorDistributionAccount]) -> Result<u64> {
     let mut total = 0u64;
     for investor in investors {
         total = MathUtil::safe_add(total, investor.locked_amount)?;
@@ -416,9 +1724,9 @@ fn get_total_locked_amount(investors: &[InvestorDistributionAccount]) -> Result<
 }
 
 /// Transfer tokens to an investor
-fn transfer_to_investor(
-    ctx: &Context<DistributeFees>,
-    investor: &InvestorDistributionAccount,
+fn transfer_to_investor<'info>(
+    ctx: &Context<'_, '_, '_, 'info, DistributeFees<'info>>,
+    investor: &InvestorDistributionAccount<'info>,
     amount: u64,
 ) -> Result<()> {
     let vault_key = ctx.accounts.vault.key();
@@ -426,19 +1734,39 @@ fn transfer_to_investor(
         VAULT_SEED,
         vault_key.as_ref(),
         INVESTOR_FEE_POS_OWNER_SEED,
-        &[ctx.bumps.position_owner_pda],
+        &[ctx.accounts.policy.position_owner_bump],
     ];
     let signer = &[&seeds[..]];
     
-    // Find the investor's ATA in remaining accounts
-    let investor_ata_info = ctx.remaining_accounts
-        .iter()
-        .find(|acc| acc.key == &investor.investor_quote_ata)
-        .ok_or(HonoraryFeeError::InvalidTreasury)?;
+    // Create the investor's quote ATA first if it does not exist yet (payer-funded)
+    if investor.create_ata {
+        create_investor_ata(ctx, investor)?;
+    }
+    
+    // Flag failures are reported with the investor index rather than as an opaque CPI error
+    let ata = &investor.investor_quote_ata;
+    if !ata.is_writable {
+        msg!("Investor {} quote ATA {} is not writable", investor.index, ata.key);
+        return err!(HonoraryFeeError::InvestorAtaNotWritable);
+    }
+    if ata.owner != &token::ID {
+        msg!("Investor {} quote ATA {} is owned by {}, not the token program", investor.index, ata.key, ata.owner);
+        return err!(HonoraryFeeError::InvestorAtaNotTokenAccount);
+    }
+    
+    // Never transfer into an account of another mint or owner, whatever the keeper supplied
+    require!(
+        is_valid_token_account(
+            &investor.investor_quote_ata,
+            &ctx.accounts.treasury.mint,
+            investor.recipient.as_ref(),
+        ),
+        HonoraryFeeError::InvalidInvestorAta
+    );
     
     let cpi_accounts = Transfer {
         from: ctx.accounts.treasury.to_account_info(),
-        to: investor_ata_info.clone(),
+        to: investor.investor_quote_ata.clone(),
         authority: ctx.accounts.position_owner_pda.to_account_info(),
     };
     
@@ -447,63 +1775,358 @@ fn transfer_to_investor(
     
     token::transfer(cpi_ctx, amount)?;
     
-    msg!("Transferred {} tokens to investor {}", amount, investor.investor_quote_ata);
+    msg!("Transferred {} tokens to investor {}", amount, investor.investor_quote_ata.key);
+    
+    Ok(())
+}
+
+/// Load the investor's payout record; investors without a record are paid untracked
+fn read_payout_record<'info>(
+    ctx: &Context<'_, '_, '_, 'info, DistributeFees<'info>>,
+    investor: &InvestorDistributionAccount<'info>,
+    payout_record: &AccountInfo<'info>,
+) -> Result<Option<InvestorPayoutRecord>> {
+    if is_uninitialized_account(payout_record) {
+        return Ok(None);
+    }
+    
+    require!(
+        payout_record.owner == &crate::ID && payout_record.is_writable,
+        HonoraryFeeError::InvalidPayoutRecord
+    );
+    
+    let record = InvestorPayoutRecord::try_deserialize(&mut &payout_record.try_borrow_data()?[..])
+        .map_err(|_| HonoraryFeeError::InvalidPayoutRecord)?;
+    require!(
+        record.vault == ctx.accounts.vault.key()
+            && record.investor_quote_ata == investor.investor_quote_ata.key(),
+        HonoraryFeeError::InvalidPayoutRecord
+    );
+    Ok(Some(record))
+}
+
+/// Create the investor stream's payout receipt for the day, funded by the crank payer
+///
+/// Returns `false` when the receipt already exists, i.e. the stream was paid earlier today.
+fn create_payout_receipt<'info>(
+    ctx: &Context<'_, '_, '_, 'info, DistributeFees<'info>>,
+    investor: &InvestorDistributionAccount<'info>,
+    receipt: &AccountInfo<'info>,
+    day_id: u64,
+) -> Result<bool> {
+    let vault = ctx.accounts.vault.key();
+    let quote_mint = ctx.accounts.treasury.mint;
+    let (expected, bump) = get_payout_receipt_pda(
+        &vault,
+        &quote_mint,
+        day_id,
+        &investor.stream_account,
+        &crate::ID,
+    );
+    require_keys_eq!(receipt.key(), expected, HonoraryFeeError::InvalidPayoutReceipt);
+    if receipt.owner == &crate::ID {
+        return Ok(false);
+    }
+    require!(receipt.is_writable, HonoraryFeeError::InvalidPayoutReceipt);
+    
+    let day_bytes = day_id.to_le_bytes();
+    let seeds = &[
+        VAULT_SEED,
+        vault.as_ref(),
+        PAYOUT_RECEIPT_SEED,
+        quote_mint.as_ref(),
+        &day_bytes,
+        investor.stream_account.as_ref(),
+        &[bump],
+    ];
+    let signer = &[&seeds[..]];
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let rent = Rent::get()?.minimum_balance(PayoutReceipt::LEN);
+    
+    // A pre-funded address cannot be created directly; top it up, allocate and assign instead
+    let current_lamports = receipt.lamports();
+    if current_lamports == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program,
+                CreateAccount {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: receipt.clone(),
+                },
+                signer,
+            ),
+            rent,
+            PayoutReceipt::LEN as u64,
+            &crate::ID,
+        )?;
+    } else {
+        if current_lamports < rent {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: receipt.clone(),
+                    },
+                ),
+                rent - current_lamports,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                Allocate { account_to_allocate: receipt.clone() },
+                signer,
+            ),
+            PayoutReceipt::LEN as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                system_program,
+                Assign { account_to_assign: receipt.clone() },
+                signer,
+            ),
+            &crate::ID,
+        )?;
+    }
+    
+    let receipt_data = PayoutReceipt {
+        vault,
+        day_id,
+        stream: investor.stream_account,
+        bump,
+    };
+    receipt_data.try_serialize(&mut &mut receipt.try_borrow_mut_data()?[..])?;
+    Ok(true)
+}
+
+/// Idempotently create an investor's quote ATA, funded by the crank payer
+fn create_investor_ata<'info>(
+    ctx: &Context<'_, '_, '_, 'info, DistributeFees<'info>>,
+    investor: &InvestorDistributionAccount<'info>,
+) -> Result<()> {
+    let (quote_mint, associated_token_program, investor_owner) = match (
+        &ctx.accounts.quote_mint,
+        &ctx.accounts.associated_token_program,
+        &investor.investor_owner,
+    ) {
+        (Some(mint), Some(program), Some(owner)) => (mint, program, owner),
+        _ => return err!(HonoraryFeeError::MissingAtaCreationAccounts),
+    };
+    
+    let cpi_accounts = Create {
+        payer: ctx.accounts.payer.to_account_info(),
+        associated_token: investor.investor_quote_ata.clone(),
+        authority: investor_owner.clone(),
+        mint: quote_mint.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    };
+    
+    let cpi_ctx = CpiContext::new(associated_token_program.to_account_info(), cpi_accounts);
+    associated_token::create_idempotent(cpi_ctx)?;
+    
+    msg!("Created quote ATA {} for investor {}", investor.investor_quote_ata.key, investor_owner.key);
     
     Ok(())
 }
 
+/// Accounts needed to close a distribution day
+pub(crate) struct DayClose<'a, 'info> {
+    pub vault: Pubkey,
+    pub policy: &'a PolicyState,
+    pub progress: &'a AccountLoader<'info, ProgressState>,
+    pub summary: &'a mut VaultSummary,
+    pub stats: &'a mut VaultStats,
+    pub treasury: AccountInfo<'info>,
+    /// Decimals of the treasury's quote mint
+    pub quote_decimals: u8,
+    pub creator_quote_ata: AccountInfo<'info>,
+    pub position_owner_pda: AccountInfo<'info>,
+    pub position_owner_bump: u8,
+    pub token_program: AccountInfo<'info>,
+}
+
+/// Part of the day's investor entitlement clipped by the day's cap
+pub(crate) fn capped_investor_excess(daily_cap: u64, investor_entitlement: u64) -> u64 {
+    if daily_cap > 0 {
+        investor_entitlement.saturating_sub(daily_cap)
+    } else {
+        0
+    }
+}
+
 /// Close the day and pay remainder to creator
-fn close_day_and_pay_creator(
-    ctx: Context<DistributeFees>,
+///
+/// With `carry_capped_excess` set, `capped_excess` is held back from the creator
+/// and carried into the next day for investors. The day's rounding residue goes
+/// where the policy's `residue_destination` routes it.
+pub(crate) fn close_day_and_pay_creator(
+    close: DayClose,
     current_ts: i64,
+    creator_only: bool,
+    capped_excess: u64,
 ) -> Result<()> {
-    let vault = ctx.accounts.vault.key();
-    let progress = &mut ctx.accounts.progress;
+    let vault = close.vault;
+    let policy = close.policy;
+    let mut progress = close.progress.load_mut()?;
+    
+    // Repeated days with no locked investors need an explicit creator acknowledgment
+    if creator_only {
+        require!(
+            progress.creator_only_streak < CREATOR_ONLY_ACK_THRESHOLD_DAYS
+                || progress.is_creator_only_acknowledged(),
+            HonoraryFeeError::CreatorAcknowledgmentRequired
+        );
+        progress.creator_only_streak = progress.creator_only_streak.saturating_add(1);
+    } else {
+        progress.creator_only_streak = 0;
+        progress.set_creator_only_acknowledged(false);
+    }
     
     // Calculate remainder for creator
-    let total_available = MathUtil::safe_add(
-        progress.daily_claimed_total,
-        progress.carry_over
+    // Dust withheld today stays in the treasury until swept
+    let remainder = MathUtil::safe_sub(
+        progress.remaining_for_distribution()?,
+        progress.daily_dust
     )?;
-    let creator_amount = MathUtil::safe_sub(total_available, progress.daily_distributed)?;
+    let capped_excess_carried = if policy.carry_capped_excess {
+        capped_excess.min(remainder)
+    } else {
+        0
+    };
+    let creator_share = MathUtil::safe_sub(remainder, capped_excess_carried)?;
+    
+    // Rounding residue leaves the creator remainder unless the policy routes it there;
+    // what the day's pages already paid the creator cannot be routed elsewhere
+    let residue_routed = match policy.residue_destination {
+        ResidueDestination::Creator => 0,
+        ResidueDestination::CarryOver | ResidueDestination::DustLedger => progress.daily_residue
+            .min(creator_share.saturating_sub(progress.creator_paid_today)),
+    };
+    let creator_amount = MathUtil::safe_sub(creator_share, residue_routed)?;
+    
+    // claimed + prior carry-over (+ donations) = investors + creator + new carry-over + withheld
+    let carried_over = match policy.residue_destination {
+        ResidueDestination::CarryOver => MathUtil::safe_add(capped_excess_carried, residue_routed)?,
+        _ => capped_excess_carried,
+    };
+    let withheld = match policy.residue_destination {
+        ResidueDestination::DustLedger => MathUtil::safe_add(progress.daily_dust, residue_routed)?,
+        _ => progress.daily_dust,
+    };
+    let total_available = progress.total_available()?;
+    if let Err(violation) = EnhancedMathUtil::validate_distribution_invariants(
+        total_available,
+        progress.daily_distributed,
+        carried_over,
+        creator_amount,
+        withheld,
+    ) {
+        emit!(ConservationViolated {
+            vault,
+            day_id: progress.day_id,
+            event_seq: progress.next_event_seq(),
+            total_available,
+            distributed_to_investors: progress.daily_distributed,
+            creator_amount,
+            carried_over,
+            withheld,
+            timestamp: current_ts,
+        });
+        return Err(violation);
+    }
     
-    if creator_amount > 0 {
-        // Transfer remainder to creator
-        let vault_key = ctx.accounts.vault.key();
+    let treasury_before = token_balance(&close.treasury)?;
+    let creator_escrowed = policy.creator_vesting_period > 0;
+    
+    // A frozen creator ATA would revert the transfer forever, so its remainder accrues instead
+    let creator_ata_frozen = is_frozen_token_account(&close.creator_quote_ata);
+    let creator_deferred = policy.defer_creator_payouts || (!creator_escrowed && creator_ata_frozen);
+    if creator_escrowed {
+        // Remainder stays in the treasury and vests to the creator over the policy period
+        progress.accrue_creator_escrow(
+            creator_amount,
+            current_ts,
+            policy.creator_vesting_period,
+        )?;
+    } else if creator_deferred {
+        // Remainder stays in the treasury until the creator claims it, less what the day's pages already paid
+        if creator_ata_frozen {
+            msg!("Creator ATA {} is frozen, accruing the remainder", close.creator_quote_ata.key);
+        }
+        progress.creator_pending = MathUtil::safe_add(
+            progress.creator_pending,
+            creator_amount.saturating_sub(progress.creator_paid_today),
+        )?;
+    } else if creator_amount > progress.creator_paid_today {
+        // Transfer remainder to creator, less what the day's pages already paid
         let seeds = &[
             VAULT_SEED,
-            vault_key.as_ref(),
+            vault.as_ref(),
             INVESTOR_FEE_POS_OWNER_SEED,
-            &[ctx.bumps.position_owner_pda],
+            &[close.position_owner_bump],
         ];
         let signer = &[&seeds[..]];
         
         let cpi_accounts = Transfer {
-            from: ctx.accounts.treasury.to_account_info(),
-            to: ctx.accounts.creator_quote_ata.to_account_info(),
-            authority: ctx.accounts.position_owner_pda.to_account_info(),
+            from: close.treasury.clone(),
+            to: close.creator_quote_ata,
+            authority: close.position_owner_pda,
         };
         
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        let cpi_ctx = CpiContext::new_with_signer(close.token_program, cpi_accounts, signer);
         
-        token::transfer(cpi_ctx, creator_amount)?;
+        token::transfer(cpi_ctx, MathUtil::safe_sub(creator_amount, progress.creator_paid_today)?)?;
     }
     
-    // Mark day as complete
-    progress.day_complete = true;
-    progress.carry_over = 0; // Reset carry over
+    // Escrowed and deferred remainders stay in the treasury; a paid remainder must leave it exactly
+    let creator_outflow = if creator_escrowed || creator_deferred {
+        0
+    } else {
+        MathUtil::safe_sub(creator_amount, progress.creator_paid_today)?
+    };
+    progress.record_treasury_outflow(treasury_before, token_balance(&close.treasury)?, creator_outflow)?;
+    
+    // Mark day as complete; the next day start may claim again
+    progress.set_day_complete(true);
+    progress.set_claimed_this_day(false);
+    progress.carry_over = carried_over;
+    if policy.residue_destination == ResidueDestination::DustLedger {
+        progress.dust_ledger = MathUtil::safe_add(progress.dust_ledger, residue_routed)?;
+    }
+    
+    // Refresh the dashboard summary and lifetime totals
+    close.summary.refresh(policy, &progress, creator_amount);
+    close.stats.total_paid_to_creator = MathUtil::safe_add(close.stats.total_paid_to_creator, creator_amount)?;
+    close.stats.distribution_days = MathUtil::safe_add(close.stats.distribution_days, 1)?;
     
     // Emit creator payout event
     emit!(CreatorPayoutDayClosed {
         vault,
+        day_id: progress.day_id,
+        event_seq: progress.next_event_seq(),
         creator_amount,
         total_claimed_today: progress.daily_claimed_total,
+        quote_decimals: close.quote_decimals,
+        donations_today: progress.daily_donations,
         total_distributed_to_investors: progress.daily_distributed,
+        dust_withheld_today: progress.daily_dust,
+        capped_excess_carried,
+        residue_destination: policy.residue_destination,
+        residue_routed,
+        creator_escrowed,
+        creator_deferred,
         timestamp: current_ts,
     });
     
-    msg!("Day complete - paid {} to creator", creator_amount);
+    if creator_escrowed {
+        msg!("Day complete - escrowed {} for the creator", creator_amount);
+    } else if creator_deferred {
+        msg!("Day complete - accrued {} for the creator", creator_amount);
+    } else {
+        msg!("Day complete - paid {} to creator", creator_amount);
+    }
     
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token,
+    token::{self, Token, TokenAccount, Transfer},
+};
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    events::DonationReceived,
+    utils::MathUtil,
+};
+
+#[derive(Accounts)]
+pub struct Donate<'info> {
+    /// Donor
+    pub donor: Signer<'info>,
+    
+    /// Donor's token account in the treasury's quote mint
+    #[account(
+        mut,
+        token::mint = treasury.mint,
+        token::authority = donor,
+    )]
+    pub donor_quote_account: Account<'info, TokenAccount>,
+    
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state account
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump = policy.bump
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state account of the treasury's quote mint
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), policy.progress_seed_for(&treasury.mint)],
+        bump = progress.load()?.bump
+    )]
+    pub progress: AccountLoader<'info, ProgressState>,
+    
+    /// PDA that owns the treasury
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), INVESTOR_FEE_POS_OWNER_SEED],
+        bump = policy.position_owner_bump
+    )]
+    /// CHECK: This is a PDA
+    pub position_owner_pda: UncheckedAccount<'info>,
+    
+    /// Treasury account receiving the donation, for either quote mint
+    #[account(
+        mut,
+        constraint = policy.is_quote_mint(&treasury.mint) @ HonoraryFeeError::InvalidQuoteMint,
+        seeds = [position_owner_pda.key().as_ref(), token_program.key().as_ref(), treasury.mint.as_ref()],
+        seeds::program = associated_token::ID,
+        bump = policy.treasury_bump_for(&treasury.mint),
+        token::mint = treasury.mint,
+        token::authority = position_owner_pda,
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<Donate>, amount: u64) -> Result<()> {
+    require!(amount > 0, HonoraryFeeError::ZeroAmount);
+    require!(!ctx.accounts.policy.sunset, HonoraryFeeError::VaultSunset);
+    
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.donor_quote_account.to_account_info(),
+        to: ctx.accounts.treasury.to_account_info(),
+        authority: ctx.accounts.donor.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+    
+    // Accounted separately from claimed fees and applied at the next day start
+    let mut progress = ctx.accounts.progress.load_mut()?;
+    progress.pending_donations = MathUtil::safe_add(progress.pending_donations, amount)?;
+    progress.total_donations = MathUtil::safe_add(progress.total_donations, amount)?;
+    progress.treasury_checkpoint = MathUtil::safe_add(progress.treasury_checkpoint, amount)?;
+    
+    emit!(DonationReceived {
+        vault: ctx.accounts.vault.key(),
+        day_id: progress.day_id,
+        event_seq: progress.next_event_seq(),
+        donor: ctx.accounts.donor.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Received donation of {} quote tokens", amount);
+    
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    events::SecondaryQuoteEnabled,
+};
+
+#[derive(Accounts)]
+pub struct EnableSecondaryQuote<'info> {
+    /// Policy authority, also funding the new accounts
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump = policy.bump,
+        has_one = authority @ HonoraryFeeError::Unauthorized
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// PDA that owns the honorary position and the treasuries
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), INVESTOR_FEE_POS_OWNER_SEED],
+        bump = policy.position_owner_bump
+    )]
+    /// CHECK: This is a PDA
+    pub position_owner_pda: UncheckedAccount<'info>,
+    
+    /// The pool's other mint, distributed as a second quote asset from now on
+    #[account(address = policy.base_mint @ HonoraryFeeError::InvalidQuoteMint)]
+    pub secondary_quote_mint: Account<'info, Mint>,
+    
+    /// Creator's token account for the secondary quote mint
+    #[account(
+        constraint = secondary_creator_quote_ata.mint == secondary_quote_mint.key() @ HonoraryFeeError::InvalidQuoteMint
+    )]
+    pub secondary_creator_quote_ata: Account<'info, TokenAccount>,
+    
+    /// Progress state for the secondary quote mint's distribution days
+    #[account(
+        init,
+        payer = authority,
+        space = ProgressState::LEN,
+        seeds = [VAULT_SEED, vault.key().as_ref(), SECONDARY_PROGRESS_SEED],
+        bump
+    )]
+    pub secondary_progress: AccountLoader<'info, ProgressState>,
+    
+    /// Treasury holding claimed secondary quote fees
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = secondary_quote_mint,
+        associated_token::authority = position_owner_pda,
+        constraint = secondary_treasury.delegate.is_none() && secondary_treasury.close_authority.is_none() @ HonoraryFeeError::TreasuryEncumbered,
+    )]
+    pub secondary_treasury: Account<'info, TokenAccount>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler(ctx: Context<EnableSecondaryQuote>) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+    require!(!policy.sunset, HonoraryFeeError::VaultSunset);
+    require!(
+        ctx.accounts.secondary_quote_mint.decimals <= MAX_QUOTE_DECIMALS,
+        HonoraryFeeError::InvalidQuoteDecimals
+    );
+    
+    // Pull claims and creator vesting settle through the primary progress account only
+    require!(
+        !policy.pull_claims && policy.creator_vesting_period == 0,
+        HonoraryFeeError::InvalidDistributionMode
+    );
+    
+    policy.secondary_quote_mint = ctx.accounts.secondary_quote_mint.key();
+    policy.secondary_creator_quote_ata = ctx.accounts.secondary_creator_quote_ata.key();
+    policy.secondary_quote_decimals = ctx.accounts.secondary_quote_mint.decimals;
+    policy.secondary_treasury_bump = get_treasury_pda(
+        &ctx.accounts.position_owner_pda.key(),
+        &policy.secondary_quote_mint,
+    ).1;
+    
+    // Account memory is zeroed by load_init; the first day can start immediately
+    let mut progress = ctx.accounts.secondary_progress.load_init()?;
+    progress.version = ProgressState::VERSION;
+    progress.reset_processed();
+    progress.set_day_complete(true);
+    progress.bump = ctx.bumps.secondary_progress;
+    let event_seq = progress.next_event_seq();
+    drop(progress);
+    
+    emit!(SecondaryQuoteEnabled {
+        vault: ctx.accounts.vault.key(),
+        day_id: 0,
+        event_seq,
+        secondary_quote_mint: policy.secondary_quote_mint,
+        secondary_treasury: ctx.accounts.secondary_treasury.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Secondary quote mint {} enabled", policy.secondary_quote_mint);
+    
+    Ok(())
+}
@@ -30,10 +30,7 @@ impl EnhancedMathUtil {
         
         for &weight in weights {
             // Calculate proportional amount
-            let raw_payout = MathUtil::safe_div(
-                MathUtil::safe_mul(total_amount, weight)?,
-                total_weight
-            )?;
+            let raw_payout = MathUtil::mul_div(total_amount, weight, total_weight)?;
             
             // Apply minimum threshold
             let actual_payout = if raw_payout >= min_payout_threshold {
@@ -74,21 +71,29 @@ impl EnhancedMathUtil {
         Ok((capped_amount, excess))
     }
     
-    /// Validate distribution invariants
+    /// Validate the end-of-day conservation invariant
+    ///
+    /// Everything the day had (`total_available`: claimed fees, prior carry-over and
+    /// donations) must be accounted for exactly by investors, the creator, the new
+    /// carry-over and what stays withheld in the treasury.
     pub fn validate_distribution_invariants(
-        total_claimed: u64,
+        total_available: u64,
         total_distributed: u64,
         carry_over: u64,
         creator_remainder: u64,
+        withheld: u64,
     ) -> Result<()> {
         let total_accounted = MathUtil::safe_add(
-            MathUtil::safe_add(total_distributed, carry_over)?,
-            creator_remainder
+            MathUtil::safe_add(
+                MathUtil::safe_add(total_distributed, carry_over)?,
+                creator_remainder
+            )?,
+            withheld
         )?;
         
         require!(
-            total_accounted <= total_claimed,
-            HonoraryFeeError::ArithmeticOverflow
+            total_accounted == total_available,
+            HonoraryFeeError::ConservationViolation
         );
         
         Ok(())
@@ -99,32 +104,25 @@ impl EnhancedMathUtil {
 pub struct ValidationUtil;
 
 impl ValidationUtil {
-    /// Validate investor account format and accessibility
-    pub fn validate_investor_accounts(remaining_accounts: &[AccountInfo]) -> Result<()> {
-        // Must have even number of accounts (stream + ATA pairs)
+    /// Validate the shape of a page's investor account list
+    ///
+    /// Every investor contributes the same number of accounts (stream and ATA, plus the optional
+    /// owner, receipt and record slots), and no stream may double as its own ATA. Per-account
+    /// contents are left to the parser, which skips unusable investors instead of failing the page.
+    pub fn validate_investor_accounts(
+        remaining_accounts: &[AccountInfo],
+        accounts_per_investor: usize,
+    ) -> Result<()> {
         require!(
-            remaining_accounts.len() % 2 == 0,
-            HonoraryFeeError::InvalidStreamAccount
+            accounts_per_investor >= 2 && remaining_accounts.len().is_multiple_of(accounts_per_investor),
+            HonoraryFeeError::MissingAccountPair
         );
         
-        // Validate each pair
-        for chunk in remaining_accounts.chunks(2) {
-            let stream_account = &chunk[0];
-            let investor_ata = &chunk[1];
-            
-            // Basic account validation
+        for chunk in remaining_accounts.chunks(accounts_per_investor) {
             require!(
-                stream_account.data_is_empty() == false,
+                chunk[0].key != chunk[1].key,
                 HonoraryFeeError::InvalidStreamAccount
             );
-            
-            require!(
-                investor_ata.data_is_empty() == false,
-                HonoraryFeeError::InvalidTreasury
-            );
-            
-            msg!("Validated investor pair: stream={}, ata={}", 
-                 stream_account.key, investor_ata.key);
         }
         
         Ok(())
@@ -146,19 +144,28 @@ impl ValidationUtil {
         Ok(is_new_day)
     }
     
+    /// Validate a requested page size against the policy bounds
+    pub fn validate_page_size(page_size: u32, min_page_size: u32, max_page_size: u32) -> Result<()> {
+        require!(page_size > 0, HonoraryFeeError::ZeroPageSize);
+        require!(
+            page_size >= min_page_size && page_size <= max_page_size,
+            HonoraryFeeError::PageSizeOutOfBounds
+        );
+        
+        Ok(())
+    }
+    
     /// Validate pagination cursor
+    ///
+    /// A page must start inside the investor list; only an empty list may start at 0.
     pub fn validate_pagination_cursor(
         cursor: u64,
-        total_accounts: usize,
-        page_size: u64,
+        investor_count: u64,
+        page_size: u32,
     ) -> Result<()> {
+        require!(page_size > 0, HonoraryFeeError::ZeroPageSize);
         require!(
-            cursor < total_accounts as u64,
-            HonoraryFeeError::InvalidPaginationCursor
-        );
-        
-        require!(
-            page_size > 0 && page_size <= 100, // Reasonable limits
+            cursor < investor_count || (cursor == 0 && investor_count == 0),
             HonoraryFeeError::InvalidPaginationCursor
         );
         
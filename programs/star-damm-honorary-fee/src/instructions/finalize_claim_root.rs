@@ -0,0 +1,208 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token,
+    token::{Token, TokenAccount},
+};
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    events::ClaimRootFinalized,
+    utils::MathUtil,
+    distribute_fees::{capped_investor_excess, close_day_and_pay_creator, DayClose},
+};
+
+#[derive(Accounts)]
+#[instruction(root: [u8; 32], leaf_count: u64)]
+pub struct FinalizeClaimRoot<'info> {
+    /// Policy authority, pays for the claim distribution account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state account
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump = policy.bump,
+        has_one = authority @ HonoraryFeeError::Unauthorized
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), PROGRESS_SEED],
+        bump = progress.load()?.bump
+    )]
+    pub progress: AccountLoader<'info, ProgressState>,
+    
+    /// Dashboard summary account, refreshed at day close
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), SUMMARY_SEED],
+        bump = summary.bump
+    )]
+    pub summary: Account<'info, VaultSummary>,
+    
+    /// Lifetime statistics account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), STATS_SEED],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, VaultStats>,
+    
+    /// The day's claim distribution
+    #[account(
+        init,
+        payer = authority,
+        space = ClaimDistribution::space(leaf_count),
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            CLAIM_DISTRIBUTION_SEED,
+            &progress.load()?.last_distribution_ts.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub claim_distribution: Account<'info, ClaimDistribution>,
+    
+    /// PDA that owns the treasury
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), INVESTOR_FEE_POS_OWNER_SEED],
+        bump = policy.position_owner_bump
+    )]
+    /// CHECK: This is a PDA
+    pub position_owner_pda: UncheckedAccount<'info>,
+    
+    /// Treasury account holding the day's fees
+    #[account(
+        mut,
+        seeds = [position_owner_pda.key().as_ref(), token_program.key().as_ref(), policy.quote_mint.as_ref()],
+        seeds::program = associated_token::ID,
+        bump = policy.treasury_bump,
+        token::mint = policy.quote_mint,
+        token::authority = position_owner_pda,
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    /// Creator's quote token account
+    #[account(
+        mut,
+        constraint = creator_quote_ata.key() == policy.creator_quote_ata @ HonoraryFeeError::InvalidTreasury
+    )]
+    pub creator_quote_ata: Account<'info, TokenAccount>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Finalize a pull-claim day: record the allocation root, then pay the creator and close the day
+///
+/// `total_locked` is the investors' still-locked total the allocations were computed from; it
+/// fixes the day's locked-weighted share like the first page of a push day does.
+pub fn handler(
+    ctx: Context<FinalizeClaimRoot>,
+    root: [u8; 32],
+    leaf_count: u64,
+    total_allocated: u64,
+    total_locked: u64,
+) -> Result<()> {
+    let current_ts = Clock::get()?.unix_timestamp;
+    let policy = &ctx.accounts.policy;
+    let mut progress = ctx.accounts.progress.load_mut()?;
+    
+    require!(policy.pull_claims, HonoraryFeeError::InvalidDistributionMode);
+    require!(!progress.is_day_complete(), HonoraryFeeError::DistributionComplete);
+    require!(leaf_count <= MAX_CLAIM_LEAVES, HonoraryFeeError::TooManyClaimLeaves);
+    
+    progress.total_locked_today = total_locked;
+    progress.eligible_share_bps = policy.distribution_order.eligible_share_bps(
+        total_locked,
+        policy.total_investor_allocation,
+        progress.investor_fee_share_bps,
+    )?;
+    
+    // Allocations cannot exceed the locked-weighted investor share of the day, after the daily cap
+    let (investor_cap, capped_excess) = claim_allocation_cap(&progress, policy.distribution_order)?;
+    require!(
+        total_allocated <= investor_cap,
+        HonoraryFeeError::ClaimAllocationExceedsCap
+    );
+    
+    // Allocations stay in the treasury until claimed
+    progress.daily_distributed = MathUtil::safe_add(progress.daily_distributed, total_allocated)?;
+    progress.claims_outstanding = MathUtil::safe_add(progress.claims_outstanding, total_allocated)?;
+    
+    let day_ts = progress.last_distribution_ts;
+    let day_id = progress.day_id;
+    let eligible_share_bps = progress.eligible_share_bps;
+    let event_seq = progress.next_event_seq();
+    drop(progress);
+    
+    let distribution = &mut ctx.accounts.claim_distribution;
+    distribution.vault = ctx.accounts.vault.key();
+    distribution.day_ts = day_ts;
+    distribution.day_id = day_id;
+    distribution.root = root;
+    distribution.leaf_count = leaf_count;
+    distribution.total_allocated = total_allocated;
+    distribution.total_claimed = 0;
+    distribution.claimed_bitmap = vec![0u8; (leaf_count as usize).div_ceil(8)];
+    distribution.bump = ctx.bumps.claim_distribution;
+    
+    emit!(ClaimRootFinalized {
+        vault: ctx.accounts.vault.key(),
+        day_id,
+        event_seq,
+        day_ts,
+        root,
+        leaf_count,
+        total_allocated,
+        total_locked,
+        eligible_share_bps,
+        timestamp: current_ts,
+    });
+    
+    let accounts = ctx.accounts;
+    close_day_and_pay_creator(
+        DayClose {
+            vault: accounts.vault.key(),
+            policy: &accounts.policy,
+            progress: &accounts.progress,
+            summary: &mut accounts.summary,
+            stats: &mut accounts.stats,
+            treasury: accounts.treasury.to_account_info(),
+            quote_decimals: accounts.policy.quote_decimals_for(&accounts.treasury.mint),
+            creator_quote_ata: accounts.creator_quote_ata.to_account_info(),
+            position_owner_pda: accounts.position_owner_pda.to_account_info(),
+            position_owner_bump: accounts.policy.position_owner_bump,
+            token_program: accounts.token_program.to_account_info(),
+        },
+        current_ts,
+        total_allocated == 0,
+        capped_excess,
+    )
+}
+
+/// Largest allocation a pull-claim day may finalize, and the part of the investor share the daily cap clips
+///
+/// Uses the day's fee share and the `eligible_share_bps` fixed from its locked total.
+pub fn claim_allocation_cap(
+    progress: &ProgressState,
+    distribution_order: DistributionOrder,
+) -> Result<(u64, u64)> {
+    let investor_share = distribution_order.investor_allocation(
+        progress.investor_fee_share_bps,
+        progress.eligible_share_bps,
+        progress.total_available()?,
+        progress.daily_distributed,
+    )?;
+    let capped_excess = capped_investor_excess(progress.daily_cap, investor_share);
+    Ok((MathUtil::safe_sub(investor_share, capped_excess)?, capped_excess))
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    validation::PoolValidator,
+    cp_amm_cpi::PositionFees,
+};
+
+#[derive(Accounts)]
+pub struct GetPendingFees<'info> {
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state account
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump = policy.bump
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// cp-amm pool of the honorary position
+    /// CHECK: Must be the vault's pool; owner and token mints checked in handler
+    #[account(address = policy.pool @ HonoraryFeeError::PoolMismatch)]
+    pub pool: UncheckedAccount<'info>,
+    
+    /// Honorary position account
+    /// CHECK: Must be the vault's position; owner and layout checked in handler
+    #[account(
+        address = policy.position @ HonoraryFeeError::InvalidPositionOwner,
+        owner = policy.cp_amm_program @ HonoraryFeeError::InvalidPositionOwner
+    )]
+    pub position: UncheckedAccount<'info>,
+}
+
+/// Fees owed to the honorary position, written to return data as `(quote_pending, base_pending)`
+pub fn handler(ctx: Context<GetPendingFees>) -> Result<()> {
+    let fees = PositionFees::try_from_bytes(&ctx.accounts.position.try_borrow_data()?)?;
+    require!(
+        fees.pool == ctx.accounts.pool.key(),
+        HonoraryFeeError::PoolNotInitialized
+    );
+    
+    // Orient token A/B fees as quote/base using the pool's mints
+    let (token_a_mint, token_b_mint) =
+        PoolValidator::extract_token_mints(&ctx.accounts.pool, &ctx.accounts.policy.cp_amm_program)?;
+    let (quote_pending, base_pending) = fees
+        .quote_and_base_pending(&token_a_mint, &token_b_mint, &ctx.accounts.policy.quote_mint)
+        .ok_or(HonoraryFeeError::InvalidQuoteMint)?;
+    
+    set_return_data(&(quote_pending, base_pending).try_to_vec()?);
+    
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    program::StarDammHonoraryFee,
+};
+
+#[derive(Accounts)]
+pub struct InitializeGlobalConfig<'info> {
+    /// Program upgrade authority, becomes the config admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Program-wide configuration (one per program)
+    #[account(
+        init,
+        payer = admin,
+        space = GlobalConfig::LEN,
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+    
+    /// This program, used to locate its program data account
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ HonoraryFeeError::Unauthorized)]
+    pub program: Program<'info, StarDammHonoraryFee>,
+    
+    /// Program data account holding the upgrade authority
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ HonoraryFeeError::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Program-wide configuration supplied at initialization and on every update
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GlobalConfigParams {
+    /// Refundable lamport deposit required to initialize a vault
+    pub init_deposit_lamports: u64,
+    /// cp-amm program IDs vaults may be initialized against (1-`MAX_ALLOWED_PROGRAMS`)
+    pub allowed_cp_amm_programs: Vec<Pubkey>,
+    /// Locker programs investor streams may be owned by (1-`MAX_ALLOWED_PROGRAMS`)
+    pub allowed_locker_programs: Vec<Pubkey>,
+    /// Bounds on the policy parameters of new vaults
+    pub bounds: ParameterBounds,
+}
+
+impl GlobalConfigParams {
+    /// Validate and write the parameters into the config account
+    pub fn apply(&self, global_config: &mut GlobalConfig) -> Result<()> {
+        self.bounds.validate()?;
+        global_config.set_allowed_programs(
+            &self.allowed_cp_amm_programs,
+            &self.allowed_locker_programs,
+        )?;
+        global_config.init_deposit_lamports = self.init_deposit_lamports;
+        global_config.bounds = self.bounds;
+        Ok(())
+    }
+}
+
+pub fn handler(
+    ctx: Context<InitializeGlobalConfig>,
+    params: GlobalConfigParams,
+) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+    global_config.admin = ctx.accounts.admin.key();
+    params.apply(global_config)?;
+    global_config.vault_count = 0;
+    global_config.bump = ctx.bumps.global_config;
+    
+    msg!(
+        "Initialized global config with admin {}, init deposit {}, {} cp-amm and {} locker programs",
+        global_config.admin,
+        params.init_deposit_lamports,
+        params.allowed_cp_amm_programs.len(),
+        params.allowed_locker_programs.len()
+    );
+    
+    Ok(())
+}
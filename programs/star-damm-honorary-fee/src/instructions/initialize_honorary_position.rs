@@ -1,13 +1,17 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token::{Mint, Token, TokenAccount},
+    token_2022::Token2022,
 };
 use crate::{
     state::*,
     error::HonoraryFeeError,
     events::HonoraryPositionInitialized,
     validation::PoolValidator,
+    utils::MathUtil,
+    cp_amm_cpi::{CreatePosition, PositionFees},
 };
 
 #[derive(Accounts)]
@@ -15,12 +19,29 @@ pub struct InitializeHonoraryPosition<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     
+    /// Program-wide configuration
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+    
+    /// Vault index page this vault is appended to
+    #[account(
+        mut,
+        seeds = [VAULT_INDEX_SEED, &global_config.current_index_page().to_le_bytes()],
+        bump = vault_index_page.bump
+    )]
+    pub vault_index_page: Account<'info, VaultIndexPage>,
+    
     /// The vault identifier (used for PDA seeds)
     /// CHECK: This is used as a seed for PDA derivation
     pub vault: UncheckedAccount<'info>,
     
     /// cp-amm pool account
     /// CHECK: Validated in handler through cp-amm integration
+    #[account(mut)]
     pub pool: UncheckedAccount<'info>,
     
     /// Quote mint (the token we collect fees in)
@@ -58,7 +79,27 @@ pub struct InitializeHonoraryPosition<'info> {
         seeds = [VAULT_SEED, vault.key().as_ref(), PROGRESS_SEED],
         bump
     )]
-    pub progress: Account<'info, ProgressState>,
+    pub progress: AccountLoader<'info, ProgressState>,
+    
+    /// Dashboard summary account
+    #[account(
+        init,
+        payer = payer,
+        space = VaultSummary::LEN,
+        seeds = [VAULT_SEED, vault.key().as_ref(), SUMMARY_SEED],
+        bump
+    )]
+    pub summary: Account<'info, VaultSummary>,
+    
+    /// Lifetime statistics account
+    #[account(
+        init,
+        payer = payer,
+        space = VaultStats::LEN,
+        seeds = [VAULT_SEED, vault.key().as_ref(), STATS_SEED],
+        bump
+    )]
+    pub stats: Account<'info, VaultStats>,
     
     /// Treasury account for holding claimed quote fees
     #[account(
@@ -66,6 +107,7 @@ pub struct InitializeHonoraryPosition<'info> {
         payer = payer,
         associated_token::mint = quote_mint,
         associated_token::authority = position_owner_pda,
+        constraint = treasury.delegate.is_none() && treasury.close_authority.is_none() @ HonoraryFeeError::TreasuryEncumbered,
     )]
     pub treasury: Account<'info, TokenAccount>,
     
@@ -74,16 +116,46 @@ pub struct InitializeHonoraryPosition<'info> {
     #[account(mut)]
     pub position: UncheckedAccount<'info>,
     
+    /// Fresh keypair for the position NFT mint, created by cp-amm
+    #[account(mut)]
+    pub position_nft_mint: Signer<'info>,
+    
+    /// Token account receiving the position NFT for `position_owner_pda`
+    /// CHECK: Created and checked by cp-amm
+    #[account(mut)]
+    pub position_nft_account: UncheckedAccount<'info>,
+    
+    /// cp-amm pool authority
+    /// CHECK: Checked by cp-amm against its pool authority PDA
+    pub pool_authority: UncheckedAccount<'info>,
+    
+    /// cp-amm event authority
+    /// CHECK: Checked by cp-amm against its event authority PDA
+    pub event_authority: UncheckedAccount<'info>,
+    
     /// cp-amm program
-    /// CHECK: This is the cp-amm program ID
+    /// CHECK: Must be in the global config's cp-amm allow-list
+    #[account(
+        constraint = global_config.is_allowed_cp_amm_program(&cp_amm_program.key()) @ HonoraryFeeError::ProgramNotAllowed
+    )]
     pub cp_amm_program: UncheckedAccount<'info>,
     
+    /// Streamflow program the vault's streams are read from
+    /// CHECK: Must be in the global config's locker allow-list
+    #[account(
+        constraint = global_config.is_allowed_locker_program(&streamflow_program.key()) @ HonoraryFeeError::ProgramNotAllowed
+    )]
+    pub streamflow_program: UncheckedAccount<'info>,
+    
     /// System program
     pub system_program: Program<'info, System>,
     
     /// Token program  
     pub token_program: Program<'info, Token>,
     
+    /// Token-2022 program, which cp-amm mints the position NFT with
+    pub token_2022_program: Program<'info, Token2022>,
+    
     /// Associated token program
     pub associated_token_program: Program<'info, AssociatedToken>,
     
@@ -91,18 +163,171 @@ pub struct InitializeHonoraryPosition<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+/// Policy parameters supplied at initialization
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct InitializeHonoraryPositionParams {
+    /// Fee share for investors in basis points (0-10000)
+    pub investor_fee_share_bps: u16,
+    /// Optional daily cap in quote tokens (0 = no cap)
+    pub daily_cap: u64,
+    /// Minimum payout threshold in lamports
+    pub min_payout_lamports: u64,
+    /// Total investor allocation minted at TGE (Y0)
+    pub total_investor_allocation: u64,
+    /// Destination for swept dust
+    pub dust_destination: DustDestination,
+    /// Seconds over which each day's creator remainder vests (0 = paid instantly)
+    pub creator_vesting_period: i64,
+    /// Smallest page size a keeper may request
+    pub min_page_size: u32,
+    /// Largest page size a keeper may request
+    pub max_page_size: u32,
+    /// Commit each day's investor set as a Merkle root and require proofs on every page
+    pub merkle_investor_set: bool,
+    /// Finalize each day's allocation as a Merkle root that investors claim against
+    pub pull_claims: bool,
+    /// Pass an investor payout record slot with every investor entry
+    pub payout_records: bool,
+    /// Pass a day-scoped payout receipt slot with every investor entry
+    pub payout_receipts: bool,
+    /// Carry investor share clipped by the daily cap into the next day
+    pub carry_capped_excess: bool,
+    /// Whether the investor or the creator share is taken first
+    pub distribution_order: DistributionOrder,
+    /// How rounding residue of pro-rata payouts is handled
+    pub rounding_mode: RoundingMode,
+    /// Optional investor fee-share schedule (empty = constant `investor_fee_share_bps`)
+    pub fee_share_schedule: Vec<FeeSharePoint>,
+    /// Start each day at this many seconds after 00:00 UTC instead of 24h after the last day start
+    pub day_anchor_offset: Option<i64>,
+    /// Largest number of investors a day or the registry may hold (at most `MAX_INVESTORS_PER_DAY`)
+    pub max_investors: u32,
+    /// Accrue creator remainders for `claim_creator_fees` instead of paying them at day close
+    pub defer_creator_payouts: bool,
+    /// Whether the minimum payout is fixed or a share of the day's investor allocation
+    pub min_payout_mode: MinPayoutMode,
+    /// Minimum payout in basis points of the day's investor allocation (0-10000, AllocationBps mode)
+    pub min_payout_bps: u16,
+    /// Seconds that must pass between cranks within a day (0 = no throttle)
+    pub min_crank_interval_seconds: u32,
+    /// Pay the creator's share for the investors covered with every page instead of only at day close
+    pub creator_paid_per_page: bool,
+    /// Wallet that created the vault's investor streams; streams from other senders are not counted
+    pub stream_sender: Pubkey,
+    /// Where the day's rounding residue is routed at day close
+    pub residue_destination: ResidueDestination,
+    /// Seconds between day starts (`DEFAULT_DISTRIBUTION_PERIOD` with `day_anchor_offset`)
+    pub distribution_period: i64,
+}
+
 pub fn handler(
     ctx: Context<InitializeHonoraryPosition>,
-    investor_fee_share_bps: u16,
-    daily_cap: u64,
-    min_payout_lamports: u64,
-    total_investor_allocation: u64,
+    params: InitializeHonoraryPositionParams,
 ) -> Result<()> {
+    let InitializeHonoraryPositionParams {
+        investor_fee_share_bps,
+        daily_cap,
+        min_payout_lamports,
+        total_investor_allocation,
+        dust_destination,
+        creator_vesting_period,
+        min_page_size,
+        max_page_size,
+        merkle_investor_set,
+        pull_claims,
+        payout_records,
+        payout_receipts,
+        carry_capped_excess,
+        distribution_order,
+        rounding_mode,
+        fee_share_schedule,
+        day_anchor_offset,
+        max_investors,
+        defer_creator_payouts,
+        min_payout_mode,
+        min_payout_bps,
+        min_crank_interval_seconds,
+        creator_paid_per_page,
+        stream_sender,
+        residue_destination,
+        distribution_period,
+    } = params;
     let vault = ctx.accounts.vault.key();
     let quote_mint = ctx.accounts.quote_mint.key();
     
     // Validate investor fee share is within bounds (0-10000 basis points)
-    require!(investor_fee_share_bps <= 10000, HonoraryFeeError::InvalidTokenOrder);
+    require!(investor_fee_share_bps <= 10000, HonoraryFeeError::InvalidInvestorFeeShare);
+    
+    // f_locked is measured against Y0, so a vault without an allocation could never pay investors
+    require!(total_investor_allocation > 0, HonoraryFeeError::ZeroInvestorAllocation);
+    require!(stream_sender != Pubkey::default(), HonoraryFeeError::InvalidStreamSender);
+    require!(creator_vesting_period >= 0, HonoraryFeeError::InvalidVestingPeriod);
+    require!(min_payout_bps <= 10000, HonoraryFeeError::InvalidMinPayoutBps);
+    require!(
+        ctx.accounts.quote_mint.decimals <= MAX_QUOTE_DECIMALS,
+        HonoraryFeeError::InvalidQuoteDecimals
+    );
+    
+    // A fixed minimum close to the cap would withhold nearly every payout as dust
+    PolicyState::validate_cap_and_min_payout(daily_cap, min_payout_mode, min_payout_lamports)?;
+    require!(
+        min_page_size > 0 && min_page_size <= max_page_size,
+        HonoraryFeeError::InvalidPageSizeBounds
+    );
+    require!(
+        !(merkle_investor_set && pull_claims),
+        HonoraryFeeError::InvestorSetModeConflict
+    );
+    require!(
+        !(defer_creator_payouts && creator_vesting_period > 0),
+        HonoraryFeeError::InvalidDistributionMode
+    );
+    require!(
+        !(creator_paid_per_page && (defer_creator_payouts || creator_vesting_period > 0)),
+        HonoraryFeeError::InvalidDistributionMode
+    );
+    
+    // Pull-claim days have no investor pages to pay the creator with
+    require!(
+        !(creator_paid_per_page && pull_claims),
+        HonoraryFeeError::InvalidDistributionMode
+    );
+    
+    // Schedule points must be in bounds and strictly increasing in time
+    require!(
+        fee_share_schedule.len() <= MAX_FEE_SHARE_POINTS,
+        HonoraryFeeError::InvalidFeeShareSchedule
+    );
+    require!(
+        fee_share_schedule.iter().all(|point| point.bps <= 10000)
+            && fee_share_schedule.windows(2).all(|w| w[0].timestamp < w[1].timestamp),
+        HonoraryFeeError::InvalidFeeShareSchedule
+    );
+    if let Some(offset) = day_anchor_offset {
+        require!((0..86400).contains(&offset), HonoraryFeeError::InvalidDayAnchorOffset);
+        // Anchored days follow the UTC calendar, so they are always 24 hours long
+        require!(
+            distribution_period == DEFAULT_DISTRIBUTION_PERIOD,
+            HonoraryFeeError::InvalidDistributionPeriod
+        );
+    }
+    require!(
+        max_investors > 0 && max_investors as u64 <= MAX_INVESTORS_PER_DAY,
+        HonoraryFeeError::InvalidMaxInvestors
+    );
+    
+    // Stay within the program-wide parameter bounds
+    let bounds = ctx.accounts.global_config.bounds;
+    require!(
+        investor_fee_share_bps <= bounds.max_investor_fee_share_bps
+            && fee_share_schedule.iter().all(|point| point.bps <= bounds.max_investor_fee_share_bps)
+            && max_page_size <= bounds.max_page_size
+            && max_investors <= bounds.max_investors
+            && creator_vesting_period <= bounds.max_creator_vesting_period
+            && distribution_period >= bounds.min_distribution_period
+            && distribution_period <= bounds.max_distribution_period,
+        HonoraryFeeError::ParameterOutOfBounds
+    );
     
     // Validate pool configuration for quote-only fees
     PoolValidator::validate_quote_only_config(
@@ -120,35 +345,154 @@ pub fn handler(
     
     // Initialize policy state
     let policy = &mut ctx.accounts.policy;
+    policy.version = PolicyState::VERSION;
     policy.investor_fee_share_bps = investor_fee_share_bps;
     policy.daily_cap = daily_cap;
     policy.min_payout_lamports = min_payout_lamports;
+    policy.min_payout_mode = min_payout_mode;
+    policy.min_payout_bps = min_payout_bps;
+    policy.pool = ctx.accounts.pool.key();
+    policy.position = ctx.accounts.position.key();
     policy.quote_mint = quote_mint;
+    policy.base_mint = ctx.accounts.base_mint.key();
     policy.creator_quote_ata = ctx.accounts.creator_quote_ata.key();
+    policy.secondary_quote_mint = Pubkey::default();
+    policy.secondary_creator_quote_ata = Pubkey::default();
+    policy.quote_decimals = ctx.accounts.quote_mint.decimals;
+    policy.secondary_quote_decimals = 0;
     policy.total_investor_allocation = total_investor_allocation;
+    policy.dust_destination = dust_destination;
+    policy.depositor = ctx.accounts.payer.key();
+    policy.authority = ctx.accounts.payer.key();
+    policy.pending_authority = Pubkey::default();
+    policy.guardian = ctx.accounts.payer.key();
+    policy.init_deposit_lamports = ctx.accounts.global_config.init_deposit_lamports;
+    policy.sunset = false;
+    policy.pause_reason = None;
+    policy.creator_vesting_period = creator_vesting_period;
+    policy.defer_creator_payouts = defer_creator_payouts;
+    policy.creator_paid_per_page = creator_paid_per_page;
+    policy.min_page_size = min_page_size;
+    policy.max_page_size = max_page_size;
+    policy.min_crank_interval_seconds = min_crank_interval_seconds;
+    policy.registry_enabled = false;
+    policy.merkle_investor_set = merkle_investor_set;
+    policy.pull_claims = pull_claims;
+    policy.payout_records = payout_records;
+    policy.payout_receipts = payout_receipts;
+    policy.carry_capped_excess = carry_capped_excess;
+    policy.distribution_order = distribution_order;
+    policy.rounding_mode = rounding_mode;
+    policy.fee_share_schedule = [FeeSharePoint::default(); MAX_FEE_SHARE_POINTS];
+    policy.fee_share_schedule[..fee_share_schedule.len()].copy_from_slice(&fee_share_schedule);
+    policy.fee_share_schedule_len = fee_share_schedule.len() as u8;
+    policy.day_anchor_offset = day_anchor_offset;
+    policy.max_investors = max_investors;
+    policy.position_owner_bump = ctx.bumps.position_owner_pda;
+    policy.treasury_bump = get_treasury_pda(&ctx.accounts.position_owner_pda.key(), &quote_mint).1;
+    policy.secondary_treasury_bump = 0;
     policy.bump = ctx.bumps.policy;
+    policy.cp_amm_program = ctx.accounts.cp_amm_program.key();
+    policy.streamflow_program = ctx.accounts.streamflow_program.key();
+    policy.stream_sender = stream_sender;
+    policy.residue_destination = residue_destination;
+    policy.distribution_period = distribution_period;
+    policy.reserved = [0; STATE_RESERVED_BYTES];
+    
+    // Hold the refundable anti-spam deposit in the policy PDA
+    let init_deposit_lamports = ctx.accounts.global_config.init_deposit_lamports;
+    if init_deposit_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.policy.to_account_info(),
+                },
+            ),
+            init_deposit_lamports,
+        )?;
+    }
+    
+    // Append the vault to the program-wide index
+    let global_config = &mut ctx.accounts.global_config;
+    let vault_index = global_config.vault_count;
+    ctx.accounts.vault_index_page.vaults.push(vault);
+    global_config.vault_count = MathUtil::safe_add(vault_index, 1)?;
     
     // Initialize progress state  
-    let progress = &mut ctx.accounts.progress;
+    let mut progress = ctx.accounts.progress.load_init()?;
+    progress.version = ProgressState::VERSION;
+    progress.investor_fee_share_bps = investor_fee_share_bps;
+    progress.eligible_share_bps = 0;
+    progress.day_starting = 0;
+    progress.claimed_this_day = 0;
+    progress.last_error_code = 0;
     progress.last_distribution_ts = 0; // Allow immediate first distribution
+    progress.day_id = 0;
+    progress.last_crank_ts = 0;
+    progress.cranks_this_day = 0;
+    progress.event_seq = 0;
+    progress.last_crank_caller = Pubkey::default();
     progress.daily_distributed = 0;
+    progress.creator_paid_today = 0;
+    progress.failed_transfer_count = 0;
     progress.carry_over = 0;
-    progress.pagination_cursor = 0;
+    progress.reset_processed();
     progress.daily_claimed_total = 0;
-    progress.day_complete = true; // Start with day complete
+    progress.daily_claimed_base = 0;
+    progress.pending_claimed = 0;
+    progress.set_day_complete(true); // Start with day complete
+    progress.daily_dust = 0;
+    progress.daily_residue = 0;
+    progress.dust_ledger = 0;
+    progress.investor_dust_owed = 0;
+    progress.pending_donations = 0;
+    progress.daily_donations = 0;
+    progress.total_donations = 0;
+    progress.creator_only_streak = 0;
+    progress.set_creator_only_acknowledged(false);
+    progress.creator_escrow_vested = 0;
+    progress.creator_escrow_unvested = 0;
+    progress.creator_escrow_last_ts = 0;
+    progress.creator_escrow_end_ts = 0;
+    progress.creator_pending = 0;
+    progress.investor_set = InvestorSetCommitment::default();
+    progress.claims_outstanding = 0;
     progress.bump = ctx.bumps.progress;
+    progress.reserved = [0; STATE_RESERVED_BYTES];
+    let event_seq = progress.next_event_seq();
+    drop(progress);
+    
+    // Initialize dashboard summary
+    let summary = &mut ctx.accounts.summary;
+    summary.vault = vault;
+    summary.quote_mint = quote_mint;
+    summary.investor_fee_share_bps = investor_fee_share_bps;
+    summary.bump = ctx.bumps.summary;
+    
+    // Initialize lifetime statistics
+    let stats = &mut ctx.accounts.stats;
+    stats.vault = vault;
+    stats.total_fees_claimed = 0;
+    stats.total_paid_to_investors = 0;
+    stats.total_paid_to_creator = 0;
+    stats.total_dust = 0;
+    stats.distribution_days = 0;
+    stats.bump = ctx.bumps.stats;
     
     // Create the honorary position via cp-amm CPI
-    // This is where we'd make the actual cp-amm call to create a position
-    // The position should be configured to only accrue quote token fees
     create_honorary_position_cpi(&ctx)?;
     
     // Emit initialization event
     emit!(HonoraryPositionInitialized {
         vault,
+        day_id: 0,
+        event_seq,
         position_owner_pda: ctx.accounts.position_owner_pda.key(),
         quote_mint,
         position: ctx.accounts.position.key(),
+        vault_index,
         timestamp: Clock::get()?.unix_timestamp,
     });
     
@@ -162,37 +506,13 @@ pub fn handler(
 }
 
 /// Create the honorary position via cp-amm CPI
+///
+/// DAMM v2 positions span the pool's whole price range, so no tick range is chosen or recorded.
 fn create_honorary_position_cpi(ctx: &Context<InitializeHonoraryPosition>) -> Result<()> {
-    // For quote-only fees, we need to create a position that only accrues fees in one token
-    // This is achieved by placing the entire liquidity range on one side of the current price
-
-    // Determine the tick range for quote-only position
-    // We need to get the current tick from the pool and position accordingly
-    let pool_data = ctx.accounts.pool.try_borrow_data()?;
-    let current_tick = PoolValidator::extract_current_tick(&pool_data)?;
-
-    // For quote-only fees, position the range entirely on one side
-    // Let's position it below current price to collect only token A fees (assuming token A is quote)
-    // In practice, this would be determined by which token is the quote token
-    let tick_lower = current_tick - 100000; // Far below current price
-    let tick_upper = current_tick - 1;       // Just below current price
-
-    // Validate that this tick range would only accrue quote fees
-    PoolValidator::validate_position_for_quote_only_fees(
-        &ctx.accounts.pool,
-        &ctx.accounts.quote_mint.key(),
-        tick_lower,
-        tick_upper,
-    )?;
-
     msg!("Creating honorary position via cp-amm CPI");
     msg!("Position owner PDA: {}", ctx.accounts.position_owner_pda.key());
     msg!("Position account: {}", ctx.accounts.position.key());
-    msg!("Tick range: [{}, {}]", tick_lower, tick_upper);
 
-    // Placeholder for actual cp-amm integration
-    // In the real implementation, this would be a CPI call like:
-    /*
     let vault_key = ctx.accounts.vault.key();
     let seeds = &[
         VAULT_SEED,
@@ -202,26 +522,32 @@ fn create_honorary_position_cpi(ctx: &Context<InitializeHonoraryPosition>) -> Re
     ];
     let signer = &[&seeds[..]];
 
-    // Note: This is pseudo-code - actual cp-amm interface may differ
-    let cpi_accounts = cp_amm::cpi::accounts::OpenPosition {
-        position: ctx.accounts.position.to_account_info(),
-        position_authority: ctx.accounts.position_owner_pda.to_account_info(),
+    CreatePosition {
+        owner: ctx.accounts.position_owner_pda.to_account_info(),
+        position_nft_mint: ctx.accounts.position_nft_mint.to_account_info(),
+        position_nft_account: ctx.accounts.position_nft_account.to_account_info(),
         pool: ctx.accounts.pool.to_account_info(),
-        token_a_vault: /* pool's token A vault */,
-        token_b_vault: /* pool's token B vault */,
-        // ... other required accounts
-    };
-
-    let cpi_program = ctx.accounts.cp_amm_program.to_account_info();
-    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        position: ctx.accounts.position.to_account_info(),
+        pool_authority: ctx.accounts.pool_authority.to_account_info(),
+        payer: ctx.accounts.payer.to_account_info(),
+        token_program: ctx.accounts.token_2022_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        event_authority: ctx.accounts.event_authority.to_account_info(),
+        program: ctx.accounts.cp_amm_program.to_account_info(),
+    }
+    .invoke_signed(signer)?;
 
-    // Create position with zero liquidity (just for fee accrual)
-    cp_amm::cpi::open_position(cpi_ctx, cp_amm::instruction::OpenPosition {
-        tick_lower_index: tick_lower,
-        tick_upper_index: tick_upper,
-        // No liquidity added - position exists only for fee accrual
-    })?;
-    */
+    // The recorded position must be the one just created in this pool
+    require!(
+        *ctx.accounts.position.owner == ctx.accounts.cp_amm_program.key(),
+        HonoraryFeeError::InvalidPositionOwner
+    );
+    let position = PositionFees::try_from_bytes(&ctx.accounts.position.try_borrow_data()?)?;
+    require!(
+        position.pool == ctx.accounts.pool.key()
+            && position.nft_mint == ctx.accounts.position_nft_mint.key(),
+        HonoraryFeeError::InvalidPositionOwner
+    );
 
     Ok(())
-}
\ No newline at end of file
+}
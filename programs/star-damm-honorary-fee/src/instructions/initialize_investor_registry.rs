@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+};
+
+#[derive(Accounts)]
+pub struct InitializeInvestorRegistry<'info> {
+    /// Policy authority, pays for the registry accounts
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump = policy.bump,
+        has_one = authority @ HonoraryFeeError::Unauthorized
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state account
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), PROGRESS_SEED],
+        bump = progress.load()?.bump
+    )]
+    pub progress: AccountLoader<'info, ProgressState>,
+    
+    /// Investor registry
+    #[account(
+        init,
+        payer = authority,
+        space = InvestorRegistry::space(policy.registry_capacity()),
+        seeds = [VAULT_SEED, vault.key().as_ref(), REGISTRY_SEED],
+        bump
+    )]
+    pub investor_registry: Account<'info, InvestorRegistry>,
+    
+    /// Locked-amount snapshot
+    #[account(
+        init,
+        payer = authority,
+        space = LockedSnapshot::space(policy.registry_capacity()),
+        seeds = [VAULT_SEED, vault.key().as_ref(), SNAPSHOT_SEED],
+        bump
+    )]
+    pub locked_snapshot: Account<'info, LockedSnapshot>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeInvestorRegistry>) -> Result<()> {
+    // Switching payout sources mid-day would mix live and snapshot weights
+    require!(ctx.accounts.progress.load()?.is_day_complete(), HonoraryFeeError::DayInProgress);
+    require!(
+        !ctx.accounts.policy.merkle_investor_set && !ctx.accounts.policy.pull_claims,
+        HonoraryFeeError::InvestorSetModeConflict
+    );
+    
+    let vault = ctx.accounts.vault.key();
+    
+    let registry = &mut ctx.accounts.investor_registry;
+    registry.vault = vault;
+    registry.investors = Vec::new();
+    registry.bump = ctx.bumps.investor_registry;
+    
+    let snapshot = &mut ctx.accounts.locked_snapshot;
+    snapshot.vault = vault;
+    snapshot.base_day_ts = ctx.accounts.progress.load()?.last_distribution_ts;
+    snapshot.locked = Vec::new();
+    snapshot.total_locked = 0;
+    snapshot.bump = ctx.bumps.locked_snapshot;
+    
+    ctx.accounts.policy.registry_enabled = true;
+    
+    msg!("Initialized investor registry for vault {}", vault);
+    
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+};
+
+#[derive(Accounts)]
+pub struct InitializePayoutRecord<'info> {
+    /// Pays for the record account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state account
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump = policy.bump
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Investor's quote token account the record tracks
+    #[account(
+        constraint = policy.is_quote_mint(&investor_quote_ata.mint) @ HonoraryFeeError::InvalidQuoteMint
+    )]
+    pub investor_quote_ata: Account<'info, TokenAccount>,
+    
+    /// Investor payout record
+    #[account(
+        init,
+        payer = payer,
+        space = InvestorPayoutRecord::LEN,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            PAYOUT_RECORD_SEED,
+            investor_quote_ata.key().as_ref(),
+        ],
+        bump
+    )]
+    pub payout_record: Account<'info, InvestorPayoutRecord>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializePayoutRecord>) -> Result<()> {
+    let record = &mut ctx.accounts.payout_record;
+    record.vault = ctx.accounts.vault.key();
+    record.investor_quote_ata = ctx.accounts.investor_quote_ata.key();
+    record.total_received = 0;
+    record.payout_count = 0;
+    record.last_payout_ts = 0;
+    record.pending_dust = 0;
+    record.bump = ctx.bumps.payout_record;
+    
+    msg!("Payout record created for investor {}", record.investor_quote_ata);
+    
+    Ok(())
+}
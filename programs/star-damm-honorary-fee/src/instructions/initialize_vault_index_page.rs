@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializeVaultIndexPage<'info> {
+    /// Pays for the index page; anyone may create the page the next vault goes on
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Program-wide configuration
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+    
+    /// Index page the next initialized vault will be appended to
+    #[account(
+        init,
+        payer = payer,
+        space = VaultIndexPage::LEN,
+        seeds = [VAULT_INDEX_SEED, &global_config.current_index_page().to_le_bytes()],
+        bump
+    )]
+    pub vault_index_page: Account<'info, VaultIndexPage>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeVaultIndexPage>) -> Result<()> {
+    let page = ctx.accounts.global_config.current_index_page();
+    let index_page = &mut ctx.accounts.vault_index_page;
+    index_page.page = page;
+    index_page.vaults = Vec::new();
+    index_page.bump = ctx.bumps.vault_index_page;
+    
+    msg!("Initialized vault index page {}", page);
+    
+    Ok(())
+}
@@ -0,0 +1,252 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer},
+    token_2022::spl_token_2022::state::Account as SplTokenAccount,
+    token_interface::TokenAccount as InterfaceTokenAccount,
+};
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    events::QuoteMintMigrated,
+    validation::PoolValidator,
+    cp_amm_cpi::{verify_position_authority, PositionFees},
+};
+
+#[derive(Accounts)]
+pub struct MigrateQuoteMint<'info> {
+    /// Policy authority, pays for the new treasury and receives the old treasury rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump = policy.bump,
+        has_one = authority @ HonoraryFeeError::Unauthorized
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), PROGRESS_SEED],
+        bump = progress.load()?.bump
+    )]
+    pub progress: AccountLoader<'info, ProgressState>,
+    
+    /// Dashboard summary account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), SUMMARY_SEED],
+        bump = summary.bump
+    )]
+    pub summary: Account<'info, VaultSummary>,
+    
+    /// PDA that owns the treasuries
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), INVESTOR_FEE_POS_OWNER_SEED],
+        bump = policy.position_owner_bump
+    )]
+    /// CHECK: This is a PDA
+    pub position_owner_pda: UncheckedAccount<'info>,
+    
+    /// Treasury for the current quote mint, closed by this instruction
+    #[account(
+        mut,
+        associated_token::mint = policy.quote_mint,
+        associated_token::authority = position_owner_pda,
+    )]
+    pub old_treasury: Account<'info, TokenAccount>,
+    
+    /// Creator's quote token account for the current quote mint
+    #[account(
+        mut,
+        constraint = old_creator_quote_ata.key() == policy.creator_quote_ata @ HonoraryFeeError::InvalidTreasury
+    )]
+    pub old_creator_quote_ata: Account<'info, TokenAccount>,
+    
+    /// cp-amm pool quoting the new mint
+    /// CHECK: Validated in handler through cp-amm integration
+    pub pool: UncheckedAccount<'info>,
+    
+    /// Honorary position in the new pool, created beforehand for `position_owner_pda`
+    /// CHECK: Owner checked here; pool and NFT holder checked in handler
+    #[account(owner = policy.cp_amm_program @ HonoraryFeeError::InvalidPositionOwner)]
+    pub new_position: UncheckedAccount<'info>,
+    
+    /// Token account holding the new position's NFT
+    pub new_position_nft_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// New quote mint
+    pub new_quote_mint: Account<'info, Mint>,
+    
+    /// Base mint of the new pool
+    pub base_mint: Account<'info, Mint>,
+    
+    /// Creator's quote token account for the new quote mint
+    #[account(
+        constraint = new_creator_quote_ata.mint == new_quote_mint.key() @ HonoraryFeeError::InvalidQuoteMint
+    )]
+    pub new_creator_quote_ata: Account<'info, TokenAccount>,
+    
+    /// Treasury for the new quote mint
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = new_quote_mint,
+        associated_token::authority = position_owner_pda,
+    )]
+    pub new_treasury: Account<'info, TokenAccount>,
+    
+    /// cp-amm program
+    /// CHECK: Must be the cp-amm program recorded in the policy
+    #[account(address = policy.cp_amm_program @ HonoraryFeeError::ProgramIdMismatch)]
+    pub cp_amm_program: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler(ctx: Context<MigrateQuoteMint>) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let old_quote_mint = ctx.accounts.policy.quote_mint;
+    let new_quote_mint = ctx.accounts.new_quote_mint.key();
+    
+    require!(!ctx.accounts.policy.sunset, HonoraryFeeError::VaultSunset);
+    require!(new_quote_mint != old_quote_mint, HonoraryFeeError::QuoteMintUnchanged);
+    require!(
+        ctx.accounts.new_quote_mint.decimals <= MAX_QUOTE_DECIMALS,
+        HonoraryFeeError::InvalidQuoteDecimals
+    );
+    require!(
+        !ctx.accounts.policy.has_secondary_quote(),
+        HonoraryFeeError::InvalidDistributionMode
+    );
+    
+    // Everything owed from the old treasury must be settled first
+    let mut progress = ctx.accounts.progress.load_mut()?;
+    require!(progress.is_day_complete(), HonoraryFeeError::DayInProgress);
+    require!(
+        progress.dust_ledger == 0
+            && progress.investor_dust_owed == 0
+            && progress.pending_donations == 0
+            && progress.carry_over == 0
+            && progress.creator_escrow_vested == 0
+            && progress.creator_escrow_unvested == 0
+            && progress.creator_pending == 0
+            && progress.claims_outstanding == 0,
+        HonoraryFeeError::UnsettledTreasury
+    );
+    let day_id = progress.day_id;
+    let event_seq = progress.next_event_seq();
+    
+    // The new pool must still guarantee quote-only fee accrual
+    PoolValidator::validate_quote_only_config(
+        &ctx.accounts.pool,
+        &ctx.accounts.cp_amm_program.key(),
+        &new_quote_mint,
+        &ctx.accounts.base_mint.key(),
+    )?;
+    
+    move_honorary_position(
+        &mut ctx.accounts.policy,
+        ctx.accounts.pool.key(),
+        ctx.accounts.new_position.key(),
+        &ctx.accounts.new_position.try_borrow_data()?,
+        &ctx.accounts.new_position_nft_account,
+        &ctx.accounts.position_owner_pda.key(),
+    )?;
+    
+    let seeds = &[
+        VAULT_SEED,
+        vault_key.as_ref(),
+        INVESTOR_FEE_POS_OWNER_SEED,
+        &[ctx.accounts.policy.position_owner_bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    // Finalize the old treasury: any residual goes to the creator, rent to the authority
+    let residual = ctx.accounts.old_treasury.amount;
+    if residual > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.old_treasury.to_account_info(),
+            to: ctx.accounts.old_creator_quote_ata.to_account_info(),
+            authority: ctx.accounts.position_owner_pda.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                signer,
+            ),
+            residual,
+        )?;
+    }
+    
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.old_treasury.to_account_info(),
+            destination: ctx.accounts.authority.to_account_info(),
+            authority: ctx.accounts.position_owner_pda.to_account_info(),
+        },
+        signer,
+    ))?;
+    
+    let policy = &mut ctx.accounts.policy;
+    policy.quote_mint = new_quote_mint;
+    policy.quote_decimals = ctx.accounts.new_quote_mint.decimals;
+    policy.base_mint = ctx.accounts.base_mint.key();
+    policy.creator_quote_ata = ctx.accounts.new_creator_quote_ata.key();
+    ctx.accounts.summary.quote_mint = new_quote_mint;
+    
+    emit!(QuoteMintMigrated {
+        vault: vault_key,
+        day_id,
+        event_seq,
+        old_quote_mint,
+        new_quote_mint,
+        new_treasury: ctx.accounts.new_treasury.key(),
+        new_position: ctx.accounts.new_position.key(),
+        residual_to_creator: residual,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Vault {} migrated quote mint {} -> {}", vault_key, old_quote_mint, new_quote_mint);
+    
+    Ok(())
+}
+
+/// Point the policy at `pool` and its honorary `position`, checked the way every crank checks it
+///
+/// Cranks verify the recorded position against the recorded pool, so both move together.
+pub fn move_honorary_position(
+    policy: &mut PolicyState,
+    pool: Pubkey,
+    position: Pubkey,
+    position_data: &[u8],
+    position_nft_account: &SplTokenAccount,
+    position_owner_pda: &Pubkey,
+) -> Result<()> {
+    verify_position_authority(
+        &PositionFees::try_from_bytes(position_data)?,
+        position_nft_account,
+        position_owner_pda,
+        &pool,
+    )?;
+    policy.pool = pool;
+    policy.position = position;
+    Ok(())
+}
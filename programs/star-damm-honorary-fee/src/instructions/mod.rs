@@ -2,8 +2,100 @@ pub mod validation;
 pub mod initialize_honorary_position;
 pub mod distribute_fees;
 pub mod enhanced_utils;
+pub mod sweep_dust;
+pub mod initialize_global_config;
+pub mod update_global_config;
+pub mod sunset_vault;
+pub mod donate;
+pub mod acknowledge_creator_only;
+pub mod claim_creator;
+pub mod migrate_quote_mint;
+pub mod simulate_distribution;
+pub mod get_pending_fees;
+pub mod initialize_investor_registry;
+pub mod register_investor;
+pub mod snapshot_locked;
+pub mod finalize_claim_root;
+pub mod claim_with_proof;
+pub mod cancel_day;
+pub mod initialize_payout_record;
+pub mod enable_secondary_quote;
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod set_guardian;
+pub mod update_policy;
+pub mod pause_vault;
+pub mod unpause_vault;
+pub mod initialize_vault_index_page;
+pub mod claim_creator_fees;
+pub mod upgrade_state;
 
 pub use validation::*;
-pub use initialize_honorary_position::{InitializeHonoraryPosition, handler as initialize_handler};
-pub use distribute_fees::{DistributeFees, handler as distribute_handler};
-pub use enhanced_utils::*;
\ No newline at end of file
+pub use initialize_honorary_position::{
+    InitializeHonoraryPosition, InitializeHonoraryPositionParams, handler as initialize_handler,
+};
+pub use distribute_fees::{DistributeFees, InvestorProof, handler as distribute_handler};
+pub use enhanced_utils::*;
+pub use sweep_dust::{SweepDust, handler as sweep_dust_handler};
+pub use initialize_global_config::{
+    InitializeGlobalConfig, GlobalConfigParams, handler as initialize_global_config_handler,
+};
+pub use update_global_config::{UpdateGlobalConfig, handler as update_global_config_handler};
+pub use sunset_vault::{SunsetVault, handler as sunset_vault_handler};
+pub use donate::{Donate, handler as donate_handler};
+pub use acknowledge_creator_only::{AcknowledgeCreatorOnly, handler as acknowledge_creator_only_handler};
+pub use claim_creator::{ClaimCreator, handler as claim_creator_handler};
+pub use migrate_quote_mint::{MigrateQuoteMint, handler as migrate_quote_mint_handler};
+pub use simulate_distribution::{
+    SimulateDistribution, DistributionSimulation, PayoutProjection, ProjectedOutcome,
+    handler as simulate_distribution_handler,
+};
+pub use get_pending_fees::{GetPendingFees, handler as get_pending_fees_handler};
+pub use initialize_investor_registry::{InitializeInvestorRegistry, handler as initialize_investor_registry_handler};
+pub use register_investor::{RegisterInvestor, handler as register_investor_handler};
+pub use snapshot_locked::{SnapshotLocked, handler as snapshot_locked_handler};
+pub use finalize_claim_root::{FinalizeClaimRoot, handler as finalize_claim_root_handler};
+pub use claim_with_proof::{ClaimWithProof, handler as claim_with_proof_handler};
+pub use cancel_day::{CancelDay, handler as cancel_day_handler};
+pub use initialize_payout_record::{InitializePayoutRecord, handler as initialize_payout_record_handler};
+pub use enable_secondary_quote::{EnableSecondaryQuote, handler as enable_secondary_quote_handler};
+pub use propose_authority::{ProposeAuthority, handler as propose_authority_handler};
+pub use accept_authority::{AcceptAuthority, handler as accept_authority_handler};
+pub use set_guardian::{SetGuardian, handler as set_guardian_handler};
+pub use update_policy::{UpdatePolicy, UpdatePolicyParams, handler as update_policy_handler};
+pub use pause_vault::{PauseVault, handler as pause_vault_handler};
+pub use unpause_vault::{UnpauseVault, handler as unpause_vault_handler};
+pub use initialize_vault_index_page::{InitializeVaultIndexPage, handler as initialize_vault_index_page_handler};
+pub use claim_creator_fees::{ClaimCreatorFees, handler as claim_creator_fees_handler};
+pub use upgrade_state::{UpgradeState, handler as upgrade_state_handler};
+
+// Anchor's #[program] macro resolves the generated client account modules from the crate root
+pub(crate) use initialize_honorary_position::__client_accounts_initialize_honorary_position;
+pub(crate) use distribute_fees::__client_accounts_distribute_fees;
+pub(crate) use sweep_dust::__client_accounts_sweep_dust;
+pub(crate) use initialize_global_config::__client_accounts_initialize_global_config;
+pub(crate) use update_global_config::__client_accounts_update_global_config;
+pub(crate) use sunset_vault::__client_accounts_sunset_vault;
+pub(crate) use donate::__client_accounts_donate;
+pub(crate) use acknowledge_creator_only::__client_accounts_acknowledge_creator_only;
+pub(crate) use claim_creator::__client_accounts_claim_creator;
+pub(crate) use migrate_quote_mint::__client_accounts_migrate_quote_mint;
+pub(crate) use simulate_distribution::__client_accounts_simulate_distribution;
+pub(crate) use get_pending_fees::__client_accounts_get_pending_fees;
+pub(crate) use initialize_investor_registry::__client_accounts_initialize_investor_registry;
+pub(crate) use register_investor::__client_accounts_register_investor;
+pub(crate) use snapshot_locked::__client_accounts_snapshot_locked;
+pub(crate) use finalize_claim_root::__client_accounts_finalize_claim_root;
+pub(crate) use claim_with_proof::__client_accounts_claim_with_proof;
+pub(crate) use cancel_day::__client_accounts_cancel_day;
+pub(crate) use initialize_payout_record::__client_accounts_initialize_payout_record;
+pub(crate) use enable_secondary_quote::__client_accounts_enable_secondary_quote;
+pub(crate) use propose_authority::__client_accounts_propose_authority;
+pub(crate) use accept_authority::__client_accounts_accept_authority;
+pub(crate) use set_guardian::__client_accounts_set_guardian;
+pub(crate) use update_policy::__client_accounts_update_policy;
+pub(crate) use pause_vault::__client_accounts_pause_vault;
+pub(crate) use unpause_vault::__client_accounts_unpause_vault;
+pub(crate) use initialize_vault_index_page::__client_accounts_initialize_vault_index_page;
+pub(crate) use claim_creator_fees::__client_accounts_claim_creator_fees;
+pub(crate) use upgrade_state::__client_accounts_upgrade_state;
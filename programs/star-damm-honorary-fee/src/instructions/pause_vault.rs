@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    events::VaultPaused,
+};
+
+#[derive(Accounts)]
+pub struct PauseVault<'info> {
+    /// Policy authority or guardian
+    pub signer: Signer<'info>,
+    
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump = policy.bump,
+        constraint = signer.key() == policy.authority || signer.key() == policy.guardian @ HonoraryFeeError::Unauthorized
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), PROGRESS_SEED],
+        bump = progress.load()?.bump
+    )]
+    pub progress: AccountLoader<'info, ProgressState>,
+}
+
+pub fn handler(ctx: Context<PauseVault>, reason: PauseReason) -> Result<()> {
+    // Re-pausing only updates the reason shown to frontends
+    let policy = &mut ctx.accounts.policy;
+    policy.pause_reason = Some(reason);
+    
+    let mut progress = ctx.accounts.progress.load_mut()?;
+    emit!(VaultPaused {
+        vault: ctx.accounts.vault.key(),
+        day_id: progress.day_id,
+        event_seq: progress.next_event_seq(),
+        reason,
+        paused_by: ctx.accounts.signer.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Distributions paused: {:?}", reason);
+    
+    Ok(())
+}
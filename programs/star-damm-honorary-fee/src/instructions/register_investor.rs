@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    events::InvestorRegistered,
+    streamflow_layout::StreamContract,
+};
+
+#[derive(Accounts)]
+pub struct RegisterInvestor<'info> {
+    /// Policy authority
+    pub authority: Signer<'info>,
+    
+    /// Program-wide configuration
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+    
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state account
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump = policy.bump,
+        has_one = authority @ HonoraryFeeError::Unauthorized
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), PROGRESS_SEED],
+        bump = progress.load()?.bump
+    )]
+    pub progress: AccountLoader<'info, ProgressState>,
+    
+    /// Investor registry
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), REGISTRY_SEED],
+        bump = investor_registry.bump
+    )]
+    pub investor_registry: Account<'info, InvestorRegistry>,
+    
+    /// Investor's Streamflow stream
+    /// CHECK: Owned by an allowed locker program and parsed as a Streamflow contract in handler
+    #[account(
+        constraint = global_config.is_allowed_locker_program(stream.owner) @ HonoraryFeeError::ProgramNotAllowed
+    )]
+    pub stream: UncheckedAccount<'info>,
+    
+    /// Investor's quote token account
+    #[account(
+        constraint = investor_quote_ata.mint == policy.quote_mint @ HonoraryFeeError::InvalidQuoteMint
+    )]
+    pub investor_quote_ata: Account<'info, TokenAccount>,
+}
+
+pub fn handler(ctx: Context<RegisterInvestor>, multiplier_bps: u16) -> Result<()> {
+    // The registry order is the payout order, so it is frozen while a day is in progress
+    require!(ctx.accounts.progress.load()?.is_day_complete(), HonoraryFeeError::DayInProgress);
+    
+    let contract = StreamContract::try_from_bytes(&ctx.accounts.stream.try_borrow_data()?)?;
+    require_keys_eq!(
+        contract.mint,
+        ctx.accounts.policy.base_mint,
+        HonoraryFeeError::StreamMintMismatch
+    );
+    require!(
+        ctx.accounts.policy.is_vault_stream_sender(&contract.sender),
+        HonoraryFeeError::ForeignStream
+    );
+    require!(
+        multiplier_bps > 0 && multiplier_bps <= MAX_MULTIPLIER_BPS,
+        HonoraryFeeError::InvalidMultiplier
+    );
+    
+    let stream = ctx.accounts.stream.key();
+    let registry = &mut ctx.accounts.investor_registry;
+    require!(
+        registry.investors.len() < ctx.accounts.policy.registry_capacity(),
+        HonoraryFeeError::RegistryFull
+    );
+    require!(
+        !registry.investors.iter().any(|investor| investor.stream == stream),
+        HonoraryFeeError::InvestorAlreadyRegistered
+    );
+    
+    registry.investors.push(RegisteredInvestor {
+        stream,
+        quote_ata: ctx.accounts.investor_quote_ata.key(),
+        multiplier_bps,
+    });
+    
+    let mut progress = ctx.accounts.progress.load_mut()?;
+    emit!(InvestorRegistered {
+        vault: ctx.accounts.vault.key(),
+        day_id: progress.day_id,
+        event_seq: progress.next_event_seq(),
+        index: (registry.investors.len() - 1) as u64,
+        stream,
+        quote_ata: ctx.accounts.investor_quote_ata.key(),
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    events::GuardianUpdated,
+};
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    /// Policy authority
+    pub authority: Signer<'info>,
+    
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump = policy.bump,
+        has_one = authority @ HonoraryFeeError::Unauthorized
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), PROGRESS_SEED],
+        bump = progress.load()?.bump
+    )]
+    pub progress: AccountLoader<'info, ProgressState>,
+}
+
+pub fn handler(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+    let previous_guardian = policy.guardian;
+    policy.guardian = guardian;
+    
+    let mut progress = ctx.accounts.progress.load_mut()?;
+    emit!(GuardianUpdated {
+        vault: ctx.accounts.vault.key(),
+        day_id: progress.day_id,
+        event_seq: progress.next_event_seq(),
+        previous_guardian,
+        new_guardian: guardian,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Policy guardian set to {}", guardian);
+    
+    Ok(())
+}
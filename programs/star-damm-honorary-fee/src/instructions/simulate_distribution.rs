@@ -0,0 +1,236 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{set_return_data, MAX_RETURN_DATA};
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    utils::MathUtil,
+    enhanced_utils::ValidationUtil,
+    distribute_fees::{locked_source, parse_investor_accounts, plan_investor_page, eligible_share_bps, min_payout_threshold, DayBudget, InvestorProof},
+};
+
+#[derive(Accounts)]
+pub struct SimulateDistribution<'info> {
+    /// Program-wide configuration
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+    
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state account
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump = policy.bump
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state account
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), PROGRESS_SEED],
+        bump = progress.load()?.bump
+    )]
+    pub progress: AccountLoader<'info, ProgressState>,
+    
+    /// Investor registry, required when the policy enables it
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), REGISTRY_SEED],
+        bump = investor_registry.bump
+    )]
+    pub investor_registry: Option<Account<'info, InvestorRegistry>>,
+    
+    /// Locked-amount snapshot, required with the registry
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), SNAPSHOT_SEED],
+        bump = locked_snapshot.bump
+    )]
+    pub locked_snapshot: Option<Account<'info, LockedSnapshot>>,
+}
+
+/// Projected result for one investor of the simulated page
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProjectedOutcome {
+    /// Payout would be transferred
+    Paid,
+    /// Payout would be withheld as dust
+    Dust,
+    /// Investor would be skipped
+    Skipped,
+}
+
+/// Projected payout for one investor
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PayoutProjection {
+    pub index: u64,
+    pub locked_amount: u64,
+    pub payout: u64,
+    pub outcome: ProjectedOutcome,
+}
+
+/// Projection of the next `distribute_fees` page, written to return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DistributionSimulation {
+    /// Whether the next crank starts a new day (its fee claim is not included)
+    pub starts_new_day: bool,
+    pub page_start: u64,
+    pub page_end: u64,
+    pub investor_count: u64,
+    /// Amount available to the day excluding fees not yet claimed
+    pub available: u64,
+    /// The day's investor allocation after the eligible share and daily cap
+    pub investor_total: u64,
+    /// Whether this page would close the day
+    pub closes_day: bool,
+    pub projections: Vec<PayoutProjection>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, SimulateDistribution<'info>>,
+    page_size: u32,
+    create_missing_atas: bool,
+    investor_set: Option<InvestorSetCommitment>,
+    proofs: Vec<InvestorProof>,
+    page_start: Option<u64>,
+) -> Result<()> {
+    let policy = &ctx.accounts.policy;
+    let progress = ctx.accounts.progress.load()?;
+    
+    ValidationUtil::validate_page_size(page_size, policy.min_page_size, policy.max_page_size)?;
+    
+    // A closed day means the next crank starts over with only carry-over and donations known
+    let starts_new_day = progress.is_day_complete();
+    let (cursor, mut budget) = if starts_new_day {
+        (
+            page_start.unwrap_or(0),
+            DayBudget {
+                already_distributed: 0,
+                remaining_for_distribution: MathUtil::safe_add(progress.carry_over, progress.pending_donations)?,
+                investor_fee_share_bps: policy.investor_fee_share_bps_at(Clock::get()?.unix_timestamp),
+                eligible_share_bps: 0,
+                daily_cap: policy.daily_cap,
+                min_payout: 0,
+            },
+        )
+    } else {
+        (
+            page_start.unwrap_or(progress.pagination_cursor),
+            DayBudget {
+                already_distributed: progress.daily_distributed,
+                remaining_for_distribution: progress.remaining_for_distribution()?,
+                investor_fee_share_bps: progress.investor_fee_share_bps,
+                eligible_share_bps: progress.eligible_share_bps,
+                daily_cap: progress.daily_cap,
+                min_payout: progress.min_payout,
+            },
+        )
+    };
+    
+    // A new Merkle day is simulated against the commitment the day-start crank would submit
+    let investor_set = if starts_new_day {
+        investor_set
+    } else {
+        Some(progress.investor_set)
+    };
+    let source = locked_source(
+        &ctx.accounts.global_config,
+        policy,
+        investor_set,
+        cursor..MathUtil::safe_add(cursor, page_size as u64)?,
+        &proofs,
+        &ctx.accounts.investor_registry,
+        &ctx.accounts.locked_snapshot,
+    )?;
+    let mut parsed = parse_investor_accounts(
+        ctx.remaining_accounts,
+        &policy.quote_mint,
+        create_missing_atas,
+        ctx.accounts.policy.payout_records,
+        ctx.accounts.policy.payout_receipts,
+        source,
+    )?;
+    require!(
+        starts_new_day || parsed.investor_count == progress.expected_investor_count,
+        HonoraryFeeError::InvestorCountMismatch
+    );
+    ValidationUtil::validate_pagination_cursor(cursor, parsed.investor_count, page_size)?;
+    
+    // A new day's split comes from this page; days in progress use the one fixed by their first page
+    if starts_new_day {
+        budget.eligible_share_bps = eligible_share_bps(
+            policy,
+            parsed.total_locked,
+            budget.investor_fee_share_bps,
+        )?;
+        budget.min_payout = min_payout_threshold(
+            policy,
+            budget.daily_cap,
+            budget.investor_fee_share_bps,
+            budget.eligible_share_bps,
+            budget.remaining_for_distribution,
+        )?;
+    } else {
+        parsed.total_locked = progress.total_locked_today;
+    }
+    let plan = plan_investor_page(
+        policy,
+        &parsed,
+        cursor,
+        page_size,
+        budget,
+        |index| !starts_new_day && progress.is_processed(index),
+    )?;
+    
+    let mut projections: Vec<PayoutProjection> = plan.payouts
+        .iter()
+        .map(|planned| PayoutProjection {
+            index: planned.investor.index,
+            locked_amount: planned.investor.locked_amount,
+            payout: planned.payout,
+            outcome: if planned.is_dust {
+                ProjectedOutcome::Dust
+            } else {
+                ProjectedOutcome::Paid
+            },
+        })
+        .collect();
+    projections.extend(plan.skipped.iter().map(|entry| PayoutProjection {
+        index: entry.index,
+        locked_amount: parsed.investors
+            .iter()
+            .find(|inv| inv.index == entry.index)
+            .map_or(0, |inv| inv.locked_amount),
+        payout: 0,
+        outcome: ProjectedOutcome::Skipped,
+    }));
+    projections.sort_by_key(|projection| projection.index);
+    
+    // The day closes once every investor index has been processed
+    let processed_after = if starts_new_day {
+        plan.page_end - plan.page_start
+    } else {
+        let newly_processed = (plan.page_start..plan.page_end)
+            .filter(|index| !progress.is_processed(*index))
+            .count() as u64;
+        MathUtil::safe_add(progress.processed_count, newly_processed)?
+    };
+    
+    let simulation = DistributionSimulation {
+        starts_new_day,
+        page_start: plan.page_start,
+        page_end: plan.page_end,
+        investor_count: parsed.investor_count,
+        available: budget.remaining_for_distribution,
+        investor_total: plan.investor_total,
+        closes_day: processed_after >= parsed.investor_count,
+        projections,
+    };
+    
+    let data = simulation.try_to_vec()?;
+    require!(data.len() <= MAX_RETURN_DATA, HonoraryFeeError::ReturnDataTooLarge);
+    set_return_data(&data);
+    
+    Ok(())
+}
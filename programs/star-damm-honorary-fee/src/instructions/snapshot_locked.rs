@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    events::LockedSnapshotCompleted,
+    utils::MathUtil,
+    distribute_fees::read_locked_amount_from_stream,
+};
+
+#[derive(Accounts)]
+pub struct SnapshotLocked<'info> {
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Progress state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), PROGRESS_SEED],
+        bump = progress.load()?.bump
+    )]
+    pub progress: AccountLoader<'info, ProgressState>,
+    
+    /// Investor registry
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), REGISTRY_SEED],
+        bump = investor_registry.bump
+    )]
+    pub investor_registry: Account<'info, InvestorRegistry>,
+    
+    /// Locked-amount snapshot
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), SNAPSHOT_SEED],
+        bump = locked_snapshot.bump
+    )]
+    pub locked_snapshot: Account<'info, LockedSnapshot>,
+}
+
+/// Permissionless: record locked amounts for registry entries starting at `start_index`
+///
+/// Remaining accounts are the registered stream accounts in registry order.
+pub fn handler(ctx: Context<SnapshotLocked>, start_index: u32) -> Result<()> {
+    let mut progress = ctx.accounts.progress.load_mut()?;
+    require!(progress.is_day_complete(), HonoraryFeeError::DayInProgress);
+    
+    let registry = &ctx.accounts.investor_registry;
+    let snapshot = &mut ctx.accounts.locked_snapshot;
+    
+    // A snapshot taken before the last day started is stale
+    if snapshot.base_day_ts != progress.last_distribution_ts {
+        snapshot.base_day_ts = progress.last_distribution_ts;
+        snapshot.locked.clear();
+        snapshot.total_locked = 0;
+    }
+    
+    require!(
+        start_index as usize == snapshot.locked.len(),
+        HonoraryFeeError::SnapshotOutOfOrder
+    );
+    
+    for stream_account in ctx.remaining_accounts {
+        let index = snapshot.locked.len();
+        let registered = registry.investors
+            .get(index)
+            .ok_or(HonoraryFeeError::InvestorNotRegistered)?;
+        require_keys_eq!(
+            stream_account.key(),
+            registered.stream,
+            HonoraryFeeError::InvestorNotRegistered
+        );
+        
+        // An unreadable stream is recorded as fully unlocked rather than blocking the snapshot
+        let locked_amount = read_locked_amount_from_stream(stream_account).unwrap_or_else(|_| {
+            msg!("Stream {} unreadable, recording zero locked", stream_account.key);
+            0
+        });
+        
+        snapshot.locked.push(locked_amount);
+        snapshot.total_locked = MathUtil::safe_add(snapshot.total_locked, locked_amount)?;
+    }
+    
+    if snapshot.is_complete_for(registry, &progress) {
+        emit!(LockedSnapshotCompleted {
+            vault: ctx.accounts.vault.key(),
+            day_id: progress.day_id,
+            event_seq: progress.next_event_seq(),
+            investor_count: snapshot.locked.len() as u64,
+            total_locked: snapshot.total_locked,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+    
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    events::VaultSunset,
+};
+
+#[derive(Accounts)]
+pub struct SunsetVault<'info> {
+    /// Account that paid the initialization deposit
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state account holding the deposit
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump = policy.bump,
+        has_one = depositor @ HonoraryFeeError::Unauthorized
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), PROGRESS_SEED],
+        bump = progress.load()?.bump
+    )]
+    pub progress: AccountLoader<'info, ProgressState>,
+}
+
+pub fn handler(ctx: Context<SunsetVault>) -> Result<()> {
+    require!(!ctx.accounts.policy.sunset, HonoraryFeeError::VaultSunset);
+    
+    // Never sunset in the middle of a distribution day
+    require!(
+        ctx.accounts.progress.load()?.is_day_complete(),
+        HonoraryFeeError::DayInProgress
+    );
+    
+    let refund = ctx.accounts.policy.init_deposit_lamports;
+    let policy = &mut ctx.accounts.policy;
+    policy.sunset = true;
+    policy.init_deposit_lamports = 0;
+    
+    // Return the deposit held above rent by the policy PDA
+    if refund > 0 {
+        policy.sub_lamports(refund)?;
+        ctx.accounts.depositor.add_lamports(refund)?;
+    }
+    
+    let mut progress = ctx.accounts.progress.load_mut()?;
+    emit!(VaultSunset {
+        vault: ctx.accounts.vault.key(),
+        day_id: progress.day_id,
+        event_seq: progress.next_event_seq(),
+        depositor: ctx.accounts.depositor.key(),
+        refunded_lamports: refund,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Vault {} sunset, refunded {} lamports", ctx.accounts.vault.key(), refund);
+    
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token,
+    token::{self, Burn, Mint, Token, TokenAccount, Transfer},
+};
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    events::DustSwept,
+    utils::MathUtil,
+};
+
+#[derive(Accounts)]
+pub struct SweepDust<'info> {
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state account
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump = policy.bump
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state account of the treasury's quote mint
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), policy.progress_seed_for(&treasury.mint)],
+        bump = progress.load()?.bump
+    )]
+    pub progress: AccountLoader<'info, ProgressState>,
+    
+    /// PDA that owns the treasury
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), INVESTOR_FEE_POS_OWNER_SEED],
+        bump = policy.position_owner_bump
+    )]
+    /// CHECK: This is a PDA
+    pub position_owner_pda: UncheckedAccount<'info>,
+    
+    /// Treasury account holding the dust
+    #[account(
+        mut,
+        constraint = policy.is_quote_mint(&treasury.mint) @ HonoraryFeeError::InvalidQuoteMint,
+        seeds = [position_owner_pda.key().as_ref(), token_program.key().as_ref(), treasury.mint.as_ref()],
+        seeds::program = associated_token::ID,
+        bump = policy.treasury_bump_for(&treasury.mint),
+        token::mint = treasury.mint,
+        token::authority = position_owner_pda,
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    /// Creator's quote token account
+    #[account(
+        mut,
+        constraint = creator_quote_ata.key() == policy.creator_quote_ata_for(&treasury.mint) @ HonoraryFeeError::InvalidTreasury
+    )]
+    pub creator_quote_ata: Account<'info, TokenAccount>,
+    
+    /// Quote mint (burned from when the destination is Burn)
+    #[account(
+        mut,
+        address = treasury.mint @ HonoraryFeeError::InvalidQuoteMint
+    )]
+    pub quote_mint: Account<'info, Mint>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<SweepDust>) -> Result<()> {
+    // Only sweep between days so the current day's accounting is untouched
+    require!(
+        ctx.accounts.progress.load()?.is_day_complete(),
+        HonoraryFeeError::DayInProgress
+    );
+    
+    let amount = ctx.accounts.progress.load()?.dust_ledger;
+    require!(amount > 0, HonoraryFeeError::NoDustToSweep);
+    
+    let destination = ctx.accounts.policy.dust_destination;
+    let vault_key = ctx.accounts.vault.key();
+    let seeds = &[
+        VAULT_SEED,
+        vault_key.as_ref(),
+        INVESTOR_FEE_POS_OWNER_SEED,
+        &[ctx.accounts.policy.position_owner_bump],
+    ];
+    let signer = &[&seeds[..]];
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let treasury_before = ctx.accounts.treasury.amount;
+    
+    // Carried dust stays in the treasury; the other destinations move it out
+    let outflow = match destination {
+        DustDestination::NextDayCarry => 0,
+        DustDestination::Creator | DustDestination::Burn => amount,
+    };
+    
+    match destination {
+        DustDestination::Creator => {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.treasury.to_account_info(),
+                to: ctx.accounts.creator_quote_ata.to_account_info(),
+                authority: ctx.accounts.position_owner_pda.to_account_info(),
+            };
+            token::transfer(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer), amount)?;
+        }
+        DustDestination::NextDayCarry => {
+            // Tokens stay in the treasury and are distributed with the next day's fees
+            let mut progress = ctx.accounts.progress.load_mut()?;
+            progress.carry_over = MathUtil::safe_add(progress.carry_over, amount)?;
+        }
+        DustDestination::Burn => {
+            let cpi_accounts = Burn {
+                mint: ctx.accounts.quote_mint.to_account_info(),
+                from: ctx.accounts.treasury.to_account_info(),
+                authority: ctx.accounts.position_owner_pda.to_account_info(),
+            };
+            token::burn(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer), amount)?;
+        }
+    }
+    
+    ctx.accounts.treasury.reload()?;
+    let mut progress = ctx.accounts.progress.load_mut()?;
+    progress.record_treasury_outflow(treasury_before, ctx.accounts.treasury.amount, outflow)?;
+    progress.dust_ledger = 0;
+    
+    emit!(DustSwept {
+        vault: vault_key,
+        day_id: progress.day_id,
+        event_seq: progress.next_event_seq(),
+        amount,
+        destination,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Swept {} dust to {:?}", amount, destination);
+    
+    Ok(())
+}
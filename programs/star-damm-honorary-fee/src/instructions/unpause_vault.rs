@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    events::VaultUnpaused,
+};
+
+#[derive(Accounts)]
+pub struct UnpauseVault<'info> {
+    /// Policy authority
+    pub authority: Signer<'info>,
+    
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump = policy.bump,
+        has_one = authority @ HonoraryFeeError::Unauthorized
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), PROGRESS_SEED],
+        bump = progress.load()?.bump
+    )]
+    pub progress: AccountLoader<'info, ProgressState>,
+}
+
+pub fn handler(ctx: Context<UnpauseVault>) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+    let previous_reason = policy.pause_reason.take().ok_or(HonoraryFeeError::VaultNotPaused)?;
+    
+    let mut progress = ctx.accounts.progress.load_mut()?;
+    emit!(VaultUnpaused {
+        vault: ctx.accounts.vault.key(),
+        day_id: progress.day_id,
+        event_seq: progress.next_event_seq(),
+        previous_reason,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Distributions resumed");
+    
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    instructions::initialize_global_config::GlobalConfigParams,
+};
+
+#[derive(Accounts)]
+pub struct UpdateGlobalConfig<'info> {
+    /// Config admin
+    pub admin: Signer<'info>,
+    
+    /// Program-wide configuration
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+        has_one = admin @ HonoraryFeeError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateGlobalConfig>,
+    params: GlobalConfigParams,
+) -> Result<()> {
+    // Deposit and bounds only affect vaults initialized after the update; the locker
+    // allow-list applies to every later investor registration
+    params.apply(&mut ctx.accounts.global_config)?;
+    
+    msg!(
+        "Updated global config: init deposit {}, {} cp-amm and {} locker programs",
+        params.init_deposit_lamports,
+        params.allowed_cp_amm_programs.len(),
+        params.allowed_locker_programs.len()
+    );
+    
+    Ok(())
+}
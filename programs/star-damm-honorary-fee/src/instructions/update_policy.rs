@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::*,
+    error::HonoraryFeeError,
+    events::{PolicyChange, PolicyUpdated},
+};
+
+#[derive(Accounts)]
+pub struct UpdatePolicy<'info> {
+    /// Policy authority
+    pub authority: Signer<'info>,
+    
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Program-wide configuration holding the parameter bounds
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+    
+    /// Policy state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump = policy.bump,
+        has_one = authority @ HonoraryFeeError::Unauthorized
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), PROGRESS_SEED],
+        bump = progress.load()?.bump
+    )]
+    pub progress: AccountLoader<'info, ProgressState>,
+}
+
+/// Policy parameters `update_policy` may change (None = keep the current value)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct UpdatePolicyParams {
+    /// Investor fee share in basis points (0-10000)
+    pub investor_fee_share_bps: Option<u16>,
+    /// Daily distribution cap (0 = no cap)
+    pub daily_cap: Option<u64>,
+    /// Whether the minimum payout is fixed or a share of the day's investor allocation
+    pub min_payout_mode: Option<MinPayoutMode>,
+    /// Fixed minimum payout
+    pub min_payout_lamports: Option<u64>,
+    /// Minimum payout in basis points of the day's investor allocation (0-10000)
+    pub min_payout_bps: Option<u16>,
+    /// Seconds between day starts
+    pub distribution_period: Option<i64>,
+}
+
+impl UpdatePolicyParams {
+    /// Validate the resulting policy and write it, returning the parameters that changed
+    ///
+    /// The checks are the ones `initialize_honorary_position` applies to the same parameters.
+    pub fn apply(&self, policy: &mut PolicyState, bounds: &ParameterBounds) -> Result<Vec<PolicyChange>> {
+        let investor_fee_share_bps = self.investor_fee_share_bps.unwrap_or(policy.investor_fee_share_bps);
+        let daily_cap = self.daily_cap.unwrap_or(policy.daily_cap);
+        let min_payout_mode = self.min_payout_mode.unwrap_or(policy.min_payout_mode);
+        let min_payout_lamports = self.min_payout_lamports.unwrap_or(policy.min_payout_lamports);
+        let min_payout_bps = self.min_payout_bps.unwrap_or(policy.min_payout_bps);
+        let distribution_period = self.distribution_period.unwrap_or(policy.distribution_period);
+    
+        require!(investor_fee_share_bps <= 10000, HonoraryFeeError::InvalidInvestorFeeShare);
+        require!(min_payout_bps <= 10000, HonoraryFeeError::InvalidMinPayoutBps);
+    
+        // A fixed minimum close to the cap would withhold nearly every payout as dust
+        PolicyState::validate_cap_and_min_payout(daily_cap, min_payout_mode, min_payout_lamports)?;
+    
+        // Anchored days follow the UTC calendar, so they are always 24 hours long
+        if policy.day_anchor_offset.is_some() {
+            require!(
+                distribution_period == DEFAULT_DISTRIBUTION_PERIOD,
+                HonoraryFeeError::InvalidDistributionPeriod
+            );
+        }
+    
+        // Stay within the program-wide parameter bounds
+        require!(
+            investor_fee_share_bps <= bounds.max_investor_fee_share_bps
+                && distribution_period >= bounds.min_distribution_period
+                && distribution_period <= bounds.max_distribution_period,
+            HonoraryFeeError::ParameterOutOfBounds
+        );
+    
+        let mut changes = Vec::new();
+        if investor_fee_share_bps != policy.investor_fee_share_bps {
+            changes.push(PolicyChange::InvestorFeeShareBps {
+                previous: policy.investor_fee_share_bps,
+                new: investor_fee_share_bps,
+            });
+        }
+        if daily_cap != policy.daily_cap {
+            changes.push(PolicyChange::DailyCap { previous: policy.daily_cap, new: daily_cap });
+        }
+        if min_payout_mode != policy.min_payout_mode {
+            changes.push(PolicyChange::MinPayoutMode { previous: policy.min_payout_mode, new: min_payout_mode });
+        }
+        if min_payout_lamports != policy.min_payout_lamports {
+            changes.push(PolicyChange::MinPayoutLamports {
+                previous: policy.min_payout_lamports,
+                new: min_payout_lamports,
+            });
+        }
+        if min_payout_bps != policy.min_payout_bps {
+            changes.push(PolicyChange::MinPayoutBps { previous: policy.min_payout_bps, new: min_payout_bps });
+        }
+        if distribution_period != policy.distribution_period {
+            changes.push(PolicyChange::DistributionPeriod {
+                previous: policy.distribution_period,
+                new: distribution_period,
+            });
+        }
+    
+        policy.investor_fee_share_bps = investor_fee_share_bps;
+        policy.daily_cap = daily_cap;
+        policy.min_payout_mode = min_payout_mode;
+        policy.min_payout_lamports = min_payout_lamports;
+        policy.min_payout_bps = min_payout_bps;
+        policy.distribution_period = distribution_period;
+        Ok(changes)
+    }
+}
+
+pub fn handler(ctx: Context<UpdatePolicy>, params: UpdatePolicyParams) -> Result<()> {
+    // The fee share and cap are copied into the progress account at day start, so a day
+    // already running keeps the values it started with
+    let bounds = ctx.accounts.global_config.bounds;
+    let changes = params.apply(&mut ctx.accounts.policy, &bounds)?;
+    if changes.is_empty() {
+        msg!("Policy unchanged");
+        return Ok(());
+    }
+    
+    let mut progress = ctx.accounts.progress.load_mut()?;
+    msg!("Updated {} policy parameters", changes.len());
+    emit!(PolicyUpdated {
+        vault: ctx.accounts.vault.key(),
+        day_id: progress.day_id,
+        event_seq: progress.next_event_seq(),
+        authority: ctx.accounts.authority.key(),
+        changes,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
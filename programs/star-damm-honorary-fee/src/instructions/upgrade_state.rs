@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::{
+    cp_amm_cpi::CP_AMM_PROGRAM_ID,
+    state::*,
+    streamflow_layout::STREAMFLOW_PROGRAM_ID,
+    error::HonoraryFeeError,
+    events::StateUpgraded,
+    program::StarDammHonoraryFee,
+};
+
+#[derive(Accounts)]
+pub struct UpgradeState<'info> {
+    /// Program upgrade authority, pays for the added space
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// The vault identifier
+    /// CHECK: Used as seed for PDA derivation
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state account; an older layout may be too short to deserialize, so it is
+    /// grown in the handler before being read
+    /// CHECK: Owner and seeds checked here; deserialized in handler once grown
+    #[account(
+        mut,
+        owner = crate::ID @ HonoraryFeeError::InvalidPolicyAccount,
+        seeds = [VAULT_SEED, vault.key().as_ref(), POLICY_SEED],
+        bump
+    )]
+    pub policy: UncheckedAccount<'info>,
+    
+    /// Progress state account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), PROGRESS_SEED],
+        bump,
+        realloc = ProgressState::LEN,
+        realloc::payer = admin,
+        realloc::zero = true
+    )]
+    pub progress: AccountLoader<'info, ProgressState>,
+    
+    /// This program, used to locate its program data account
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ HonoraryFeeError::Unauthorized)]
+    pub program: Program<'info, StarDammHonoraryFee>,
+    
+    /// Program data account holding the upgrade authority
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ HonoraryFeeError::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow a live vault's policy and progress accounts to the current layouts
+///
+/// Space added by the realloc is zeroed, so fields appended after launch start at
+/// their zero value (0, false, None or the first enum variant).
+pub fn handler(ctx: Context<UpgradeState>) -> Result<()> {
+    let policy_info = ctx.accounts.policy.to_account_info();
+    if policy_info.data_len() < PolicyState::LEN {
+        // Same steps as Anchor's realloc constraint: top up rent, then grow with zeroed space
+        let rent = Rent::get()?.minimum_balance(PolicyState::LEN);
+        let shortfall = rent.saturating_sub(policy_info.lamports());
+        if shortfall > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.admin.to_account_info(),
+                        to: policy_info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        policy_info.realloc(PolicyState::LEN, true)?;
+    }
+    
+    let mut policy_data = policy_info.try_borrow_mut_data()?;
+    let mut policy = PolicyState::try_deserialize(&mut &policy_data[..])
+        .map_err(|_| error!(HonoraryFeeError::InvalidPolicyAccount))?;
+    let previous_policy_version = policy.version;
+    policy.version = PolicyState::VERSION;
+    
+    // Vaults created before the program IDs were recorded used the canonical deployments
+    if policy.cp_amm_program == Pubkey::default() {
+        policy.cp_amm_program = CP_AMM_PROGRAM_ID;
+    }
+    if policy.streamflow_program == Pubkey::default() {
+        policy.streamflow_program = STREAMFLOW_PROGRAM_ID;
+    }
+    if policy.distribution_period == 0 {
+        policy.distribution_period = DEFAULT_DISTRIBUTION_PERIOD;
+    }
+    
+    // Treasury bumps are searched once here so cranks can verify the treasuries directly
+    let position_owner = Pubkey::create_program_address(
+        &[
+            VAULT_SEED,
+            ctx.accounts.vault.key().as_ref(),
+            INVESTOR_FEE_POS_OWNER_SEED,
+            &[policy.position_owner_bump],
+        ],
+        &crate::ID,
+    ).map_err(|_| error!(HonoraryFeeError::InvalidPolicyAccount))?;
+    if policy.treasury_bump == 0 {
+        policy.treasury_bump = get_treasury_pda(&position_owner, &policy.quote_mint).1;
+    }
+    if policy.has_secondary_quote() && policy.secondary_treasury_bump == 0 {
+        policy.secondary_treasury_bump = get_treasury_pda(&position_owner, &policy.secondary_quote_mint).1;
+    }
+    policy.try_serialize(&mut &mut policy_data[..])?;
+    drop(policy_data);
+    
+    let mut progress = ctx.accounts.progress.load_mut()?;
+    let previous_progress_version = progress.version;
+    progress.version = ProgressState::VERSION;
+    
+    emit!(StateUpgraded {
+        vault: ctx.accounts.vault.key(),
+        day_id: progress.day_id,
+        event_seq: progress.next_event_seq(),
+        previous_policy_version,
+        previous_progress_version,
+        policy_len: PolicyState::LEN as u32,
+        progress_len: ProgressState::LEN as u32,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!(
+        "Upgraded vault state: policy v{} -> v{}, progress v{} -> v{}",
+        previous_policy_version,
+        PolicyState::VERSION,
+        previous_progress_version,
+        ProgressState::VERSION
+    );
+    
+    Ok(())
+}
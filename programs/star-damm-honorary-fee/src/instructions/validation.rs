@@ -1,63 +1,92 @@
 use anchor_lang::prelude::*;
 use crate::error::HonoraryFeeError;
 
-/// Simplified LbPair structure for token mint extraction
-/// Based on Meteora DLMM IDL - only including fields we need
+/// Fields of a cp-amm (DAMM v2) `Pool` account used by the vault
 #[derive(Clone, Debug, Default, PartialEq)]
-pub struct LbPair {
-    /// Token X mint
-    pub token_x_mint: Pubkey,
-    /// Token Y mint
-    pub token_y_mint: Pubkey,
-    /// Active bin ID (current price bin)
-    pub active_id: i32,
+pub struct CpAmmPool {
+    /// Token A mint
+    pub token_a_mint: Pubkey,
+    /// Token B mint
+    pub token_b_mint: Pubkey,
+    /// Pool vault holding token A
+    pub token_a_vault: Pubkey,
+    /// Pool vault holding token B
+    pub token_b_vault: Pubkey,
+    /// Lower end of the pool's price range (Q64.64 square root price)
+    pub sqrt_min_price: u128,
+    /// Upper end of the pool's price range (Q64.64 square root price)
+    pub sqrt_max_price: u128,
+    /// Current square root price (Q64.64)
+    pub sqrt_price: u128,
+    /// Pool status (0 = enabled, otherwise trading is disabled)
+    pub pool_status: u8,
 }
 
-impl LbPair {
-    /// Size of the LbPair account in bytes
-    pub const LEN: usize = 32 + 32 + 4 + 752; // tokenXMint + tokenYMint + activeId + other fields
+/// Byte offsets of the `Pool` fields, including the 8-byte discriminator (zero-copy, `repr(C)`)
+pub mod pool_offsets {
+    /// `PoolFeesStruct` (160 bytes) follows the discriminator
+    pub const POOL_FEES: usize = 8;
+    pub const TOKEN_A_MINT: usize = 168;
+    pub const TOKEN_B_MINT: usize = 200;
+    pub const TOKEN_A_VAULT: usize = 232;
+    pub const TOKEN_B_VAULT: usize = 264;
+    pub const WHITELISTED_VAULT: usize = 296;
+    pub const PARTNER: usize = 328;
+    pub const LIQUIDITY: usize = 360;
+    pub const SQRT_MIN_PRICE: usize = 424;
+    pub const SQRT_MAX_PRICE: usize = 440;
+    pub const SQRT_PRICE: usize = 456;
+    pub const ACTIVATION_POINT: usize = 472;
+    pub const ACTIVATION_TYPE: usize = 480;
+    pub const POOL_STATUS: usize = 481;
+
+    /// End of the last field read by this module
+    pub const PARSED_END: usize = 482;
+}
+
+impl CpAmmPool {
+    /// Anchor account discriminator of `Pool` (`sha256("account:Pool")[..8]`)
+    pub const DISCRIMINATOR: [u8; 8] = [241, 154, 109, 4, 17, 177, 109, 188];
 
-    /// Deserialize LbPair from account data
+    /// Deserialize a cp-amm pool from account data
+    ///
+    /// The account must carry the `Pool` discriminator, so any other cp-amm account
+    /// (positions, configs) is rejected instead of being read at the wrong offsets.
     pub fn try_deserialize(data: &[u8]) -> Result<Self> {
-        if data.len() < Self::LEN {
+        if data.len() < pool_offsets::PARSED_END {
             return err!(HonoraryFeeError::PoolNotInitialized);
         }
-
-        // Based on IDL structure analysis, the key fields are at these offsets:
-        // discriminator (8) + parameters (64) + vParameters (64) + bumpSeed (1) + binStepSeed (2)
-        // + tokenXMint (32) + tokenYMint (32) + reserveX (32) + reserveY (32) + ...
-        // + activeId (4) at some offset
-
-        let base_offset = 8 + 64 + 64 + 1 + 2; // Skip to tokenXMint
-        let token_x_offset = base_offset;
-        let token_y_offset = token_x_offset + 32;
-
-        // activeId comes after several other fields, approximately at offset ~200
-        // This is an approximation - would need exact IDL offset calculation
-        let active_id_offset = 200;
-
-        if data.len() < token_y_offset + 32 || data.len() < active_id_offset + 4 {
-            return err!(HonoraryFeeError::PoolNotInitialized);
+        if data[..8] != Self::DISCRIMINATOR {
+            return err!(HonoraryFeeError::InvalidPoolAccount);
         }
 
-        let token_x_mint = Pubkey::try_from(&data[token_x_offset..token_x_offset + 32])
-            .map_err(|_| HonoraryFeeError::PoolNotInitialized)?;
-
-        let token_y_mint = Pubkey::try_from(&data[token_y_offset..token_y_offset + 32])
-            .map_err(|_| HonoraryFeeError::PoolNotInitialized)?;
-
-        let active_id = i32::from_le_bytes(
-            data[active_id_offset..active_id_offset + 4]
-                .try_into()
-                .map_err(|_| HonoraryFeeError::PoolNotInitialized)?
-        );
+        let pubkey_at = |offset: usize| Pubkey::try_from(&data[offset..offset + 32]).unwrap();
+        let u128_at = |offset: usize| u128::from_le_bytes(data[offset..offset + 16].try_into().unwrap());
 
         Ok(Self {
-            token_x_mint,
-            token_y_mint,
-            active_id,
+            token_a_mint: pubkey_at(pool_offsets::TOKEN_A_MINT),
+            token_b_mint: pubkey_at(pool_offsets::TOKEN_B_MINT),
+            token_a_vault: pubkey_at(pool_offsets::TOKEN_A_VAULT),
+            token_b_vault: pubkey_at(pool_offsets::TOKEN_B_VAULT),
+            sqrt_min_price: u128_at(pool_offsets::SQRT_MIN_PRICE),
+            sqrt_max_price: u128_at(pool_offsets::SQRT_MAX_PRICE),
+            sqrt_price: u128_at(pool_offsets::SQRT_PRICE),
+            pool_status: data[pool_offsets::POOL_STATUS],
         })
     }
+
+    /// Order a quote-side and an other-side value as `(token_a, token_b)`
+    ///
+    /// Returns `None` when neither pool mint is the quote mint.
+    pub fn orient_accounts<T>(&self, quote_mint: &Pubkey, quote: T, other: T) -> Option<(T, T)> {
+        if self.token_a_mint == *quote_mint {
+            Some((quote, other))
+        } else if self.token_b_mint == *quote_mint {
+            Some((other, quote))
+        } else {
+            None
+        }
+    }
 }
 
 /// Pool validator for DAMM v2 quote-only fee accrual validation
@@ -78,21 +107,21 @@ impl PoolValidator {
         }
 
         // Extract pool data to validate token configuration
-        let lb_pair = LbPair::try_deserialize(&pool_account_info.data.borrow())?;
+        let pool = CpAmmPool::try_deserialize(&pool_account_info.data.borrow())?;
 
         // Validate that quote and base tokens match pool configuration
-        let has_quote_token = lb_pair.token_x_mint == *quote_token_mint ||
-                             lb_pair.token_y_mint == *quote_token_mint;
-        let has_base_token = lb_pair.token_x_mint == *base_token_mint ||
-                            lb_pair.token_y_mint == *base_token_mint;
+        let has_quote_token = pool.token_a_mint == *quote_token_mint ||
+                             pool.token_b_mint == *quote_token_mint;
+        let has_base_token = pool.token_a_mint == *base_token_mint ||
+                            pool.token_b_mint == *base_token_mint;
 
         if !has_quote_token || !has_base_token {
-            return err!(HonoraryFeeError::InvalidTokenOrder);
+            return err!(HonoraryFeeError::PoolMintMismatch);
         }
 
         // Ensure quote and base tokens are different
         if quote_token_mint == base_token_mint {
-            return err!(HonoraryFeeError::InvalidTokenOrder);
+            return err!(HonoraryFeeError::QuoteMintIsBaseMint);
         }
 
         // Additional validations could include:
@@ -104,7 +133,6 @@ impl PoolValidator {
     }
 
     /// Extracts token mint addresses from the DAMM v2 pool
-    /// Based on Meteora DLMM lbPair structure with tokenXMint and tokenYMint fields
     pub fn extract_token_mints(
         pool_account_info: &AccountInfo,
         cp_amm_program: &Pubkey,
@@ -114,46 +142,9 @@ impl PoolValidator {
             return err!(HonoraryFeeError::PoolNotInitialized);
         }
 
-        // Deserialize the lbPair account to extract token mints
-        let lb_pair = LbPair::try_deserialize(&pool_account_info.data.borrow())?;
+        let pool = CpAmmPool::try_deserialize(&pool_account_info.data.borrow())?;
 
-        Ok((lb_pair.token_x_mint, lb_pair.token_y_mint))
-    }
-
-    /// Calculates the tick range required for quote-only fee accrual
-    /// For quote-only fees, position liquidity on one side of current price:
-    /// - If quote is token X: position below current price (collects when price falls)
-    /// - If quote is token Y: position above current price (collects when price rises)
-    pub fn calculate_quote_only_tick_range(
-        pool_account_info: &AccountInfo,
-        _cp_amm_program: &Pubkey,
-        quote_token_mint: &Pubkey,
-    ) -> Result<(i32, i32)> {
-        // First extract pool data to get current active bin and token order
-        let lb_pair = LbPair::try_deserialize(&pool_account_info.data.borrow())?;
-
-        // Determine if quote token is token X or token Y
-        let is_quote_token_x = lb_pair.token_x_mint == *quote_token_mint;
-        let is_quote_token_y = lb_pair.token_y_mint == *quote_token_mint;
-
-        if !is_quote_token_x && !is_quote_token_y {
-            return err!(HonoraryFeeError::InvalidQuoteMint);
-        }
-
-        // Calculate quote-only tick range
-        if is_quote_token_x {
-            // Position below current price to collect quote (token X) fees
-            // Lower tick: minimum possible, Upper tick: current active bin
-            let lower_tick = -887272; // Minimum tick
-            let upper_tick = lb_pair.active_id;
-            Ok((lower_tick, upper_tick))
-        } else {
-            // Position above current price to collect quote (token Y) fees
-            // Lower tick: current active bin, Upper tick: maximum possible
-            let lower_tick = lb_pair.active_id;
-            let upper_tick = 887272; // Maximum tick
-            Ok((lower_tick, upper_tick))
-        }
+        Ok((pool.token_a_mint, pool.token_b_mint))
     }
 
     /// Detects if claimed fees contain any base token fees
@@ -168,7 +159,7 @@ impl PoolValidator {
         let is_token_b_quote = quote_token_mint == token_b_mint;
 
         if !is_token_a_quote && !is_token_b_quote {
-            return err!(HonoraryFeeError::InvalidTokenOrder);
+            return err!(HonoraryFeeError::PoolMintMismatch);
         }
 
         if is_token_a_quote && claim_amount_b > 0 {
@@ -183,52 +174,21 @@ impl PoolValidator {
     }
 
     /// Validates pool state for fee distribution
+    ///
+    /// The pool must be a cp-amm account and still enabled; a disabled pool is reported so
+    /// keepers can tell a paused pool from an empty claim.
     pub fn validate_pool_for_distribution(
         pool_account_info: &AccountInfo,
         cp_amm_program: &Pubkey,
     ) -> Result<()> {
         if pool_account_info.owner != cp_amm_program {
-            return err!(HonoraryFeeError::InvalidStreamAccount);
-        }
-
-        Ok(())
-    }
-
-    /// Extracts the current active tick/bin ID from pool data
-    pub fn extract_current_tick(pool_data: &[u8]) -> Result<i32> {
-        let lb_pair = LbPair::try_deserialize(pool_data)?;
-        Ok(lb_pair.active_id)
-    }
-
-    /// Validates that a position with given tick range would only accrue quote token fees
-    pub fn validate_position_for_quote_only_fees(
-        pool_account_info: &AccountInfo,
-        quote_token_mint: &Pubkey,
-        tick_lower: i32,
-        tick_upper: i32,
-    ) -> Result<()> {
-        let lb_pair = LbPair::try_deserialize(&pool_account_info.data.borrow())?;
-        let current_tick = lb_pair.active_id;
-
-        // Determine which token is the quote token
-        let is_quote_token_x = lb_pair.token_x_mint == *quote_token_mint;
-        let is_quote_token_y = lb_pair.token_y_mint == *quote_token_mint;
-
-        if !is_quote_token_x && !is_quote_token_y {
-            return err!(HonoraryFeeError::InvalidTokenOrder);
+            return err!(HonoraryFeeError::PoolNotInitialized);
         }
 
-        // For quote-only fees, the position should be entirely on one side of current price
-        if is_quote_token_x {
-            // Quote is token X: position should be below current price
-            if tick_upper >= current_tick {
-                return err!(HonoraryFeeError::InvalidTokenOrder);
-            }
-        } else {
-            // Quote is token Y: position should be above current price
-            if tick_lower <= current_tick {
-                return err!(HonoraryFeeError::InvalidTokenOrder);
-            }
+        let pool = CpAmmPool::try_deserialize(&pool_account_info.data.borrow())?;
+        if pool.pool_status != 0 {
+            msg!("Pool {} is disabled (status {})", pool_account_info.key, pool.pool_status);
+            return err!(HonoraryFeeError::PoolDisabled);
         }
 
         Ok(())
@@ -5,6 +5,8 @@ mod error;
 mod events;
 mod utils;
 mod instructions;
+pub mod cp_amm_cpi;
+pub mod streamflow_layout;
 
 pub use state::*;
 pub use error::*;
@@ -18,28 +20,198 @@ declare_id!("AQUVRgoaGsoy2uGnzkSDBoEVEJox2XT6Vna3Y9xKKwFZ");
 pub mod star_damm_honorary_fee {
     use super::*;
 
+    /// One-time initialization of the program-wide configuration (upgrade authority only)
+    pub fn initialize_global_config(
+        ctx: Context<InitializeGlobalConfig>,
+        params: GlobalConfigParams,
+    ) -> Result<()> {
+        instructions::initialize_global_config_handler(ctx, params)
+    }
+
+    /// Update the program-wide configuration (config admin only)
+    pub fn update_global_config(
+        ctx: Context<UpdateGlobalConfig>,
+        params: GlobalConfigParams,
+    ) -> Result<()> {
+        instructions::update_global_config_handler(ctx, params)
+    }
+
     /// Initialize the honorary fee position and policy
     pub fn initialize_honorary_position(
         ctx: Context<InitializeHonoraryPosition>,
-        investor_fee_share_bps: u16,
-        daily_cap: u64,
-        min_payout_lamports: u64,
-        total_investor_allocation: u64,
+        params: InitializeHonoraryPositionParams,
+    ) -> Result<()> {
+        instructions::initialize_handler(ctx, params)
+    }
+
+    /// Permissionless crank to claim and distribute fees (supports pagination)
+    pub fn distribute_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, DistributeFees<'info>>,
+        page_size: u32,
+        create_missing_atas: bool,
+        investor_set: Option<InvestorSetCommitment>,
+        proofs: Vec<InvestorProof>,
+        page_start: Option<u64>,
+        expected_investor_count: Option<u64>,
     ) -> Result<()> {
-        instructions::initialize_handler(
+        instructions::distribute_handler(
             ctx,
-            investor_fee_share_bps,
-            daily_cap,
-            min_payout_lamports,
-            total_investor_allocation,
+            page_size,
+            create_missing_atas,
+            investor_set,
+            proofs,
+            page_start,
+            expected_investor_count,
         )
     }
 
-    /// Permissionless crank to claim and distribute fees (supports pagination)
-    pub fn distribute_fees(
-        ctx: Context<DistributeFees>,
+    /// Permissionless sweep of the dust ledger to the policy-defined destination
+    pub fn sweep_dust(ctx: Context<SweepDust>) -> Result<()> {
+        instructions::sweep_dust_handler(ctx)
+    }
+
+    /// Sunset the vault and refund the initialization deposit
+    pub fn sunset_vault(ctx: Context<SunsetVault>) -> Result<()> {
+        instructions::sunset_vault_handler(ctx)
+    }
+
+    /// Donate quote tokens to the treasury, distributed with the next day's fees
+    pub fn donate(ctx: Context<Donate>, amount: u64) -> Result<()> {
+        instructions::donate_handler(ctx, amount)
+    }
+
+    /// Creator acknowledgment allowing creator-only payouts to continue
+    pub fn acknowledge_creator_only(ctx: Context<AcknowledgeCreatorOnly>) -> Result<()> {
+        instructions::acknowledge_creator_only_handler(ctx)
+    }
+
+    /// Claim the vested part of the creator escrow
+    pub fn claim_creator(ctx: Context<ClaimCreator>) -> Result<()> {
+        instructions::claim_creator_handler(ctx)
+    }
+
+    /// Move the vault to a new quote mint (policy authority only)
+    pub fn migrate_quote_mint(ctx: Context<MigrateQuoteMint>) -> Result<()> {
+        instructions::migrate_quote_mint_handler(ctx)
+    }
+
+    /// Preview the next distribute_fees page without moving tokens (result in return data)
+    pub fn simulate_distribution<'info>(
+        ctx: Context<'_, '_, '_, 'info, SimulateDistribution<'info>>,
         page_size: u32,
+        create_missing_atas: bool,
+        investor_set: Option<InvestorSetCommitment>,
+        proofs: Vec<InvestorProof>,
+        page_start: Option<u64>,
+    ) -> Result<()> {
+        instructions::simulate_distribution_handler(
+            ctx,
+            page_size,
+            create_missing_atas,
+            investor_set,
+            proofs,
+            page_start,
+        )
+    }
+
+    /// Report the honorary position's owed fees as `(quote_pending, base_pending)` in return data
+    pub fn get_pending_fees(ctx: Context<GetPendingFees>) -> Result<()> {
+        instructions::get_pending_fees_handler(ctx)
+    }
+
+    /// Create the investor registry and locked snapshot, switching the vault to registry payouts
+    pub fn initialize_investor_registry(ctx: Context<InitializeInvestorRegistry>) -> Result<()> {
+        instructions::initialize_investor_registry_handler(ctx)
+    }
+
+    /// Append an investor with its weight multiplier to the registry (policy authority only)
+    pub fn register_investor(ctx: Context<RegisterInvestor>, multiplier_bps: u16) -> Result<()> {
+        instructions::register_investor_handler(ctx, multiplier_bps)
+    }
+
+    /// Permissionless: record registered investors' locked amounts for the next day
+    pub fn snapshot_locked(ctx: Context<SnapshotLocked>, start_index: u32) -> Result<()> {
+        instructions::snapshot_locked_handler(ctx, start_index)
+    }
+
+    /// Close a pull-claim day with its allocation Merkle root (policy authority only)
+    pub fn finalize_claim_root(
+        ctx: Context<FinalizeClaimRoot>,
+        root: [u8; 32],
+        leaf_count: u64,
+        total_allocated: u64,
+        total_locked: u64,
+    ) -> Result<()> {
+        instructions::finalize_claim_root_handler(ctx, root, leaf_count, total_allocated, total_locked)
+    }
+
+    /// Claim one allocation of a pull-claim day with its Merkle proof
+    pub fn claim_with_proof(
+        ctx: Context<ClaimWithProof>,
+        index: u64,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
     ) -> Result<()> {
-        instructions::distribute_handler(ctx, page_size)
+        instructions::claim_with_proof_handler(ctx, index, amount, proof)
+    }
+
+    /// Abort the day in progress, rolling its unpaid amount into carry-over (policy authority only)
+    pub fn cancel_day(ctx: Context<CancelDay>) -> Result<()> {
+        instructions::cancel_day_handler(ctx)
+    }
+
+    /// Create the lifetime payout record for an investor quote ATA
+    pub fn initialize_payout_record(ctx: Context<InitializePayoutRecord>) -> Result<()> {
+        instructions::initialize_payout_record_handler(ctx)
+    }
+
+    /// Distribute the pool's other mint as a second quote asset (policy authority only)
+    pub fn enable_secondary_quote(ctx: Context<EnableSecondaryQuote>) -> Result<()> {
+        instructions::enable_secondary_quote_handler(ctx)
+    }
+
+    /// Propose a new policy authority, who must accept before taking over (policy authority only)
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::propose_authority_handler(ctx, new_authority)
+    }
+
+    /// Accept a proposed policy authority transfer (pending authority only)
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority_handler(ctx)
+    }
+
+    /// Set the guardian allowed to use the vault's emergency controls (policy authority only)
+    pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+        instructions::set_guardian_handler(ctx, guardian)
+    }
+
+    /// Change the vault's fee share, cap, minimum payout and distribution period (policy authority only)
+    pub fn update_policy(ctx: Context<UpdatePolicy>, params: UpdatePolicyParams) -> Result<()> {
+        instructions::update_policy_handler(ctx, params)
+    }
+
+    /// Pause distributions with a reason shown to frontends (policy authority or guardian)
+    pub fn pause_vault(ctx: Context<PauseVault>, reason: PauseReason) -> Result<()> {
+        instructions::pause_vault_handler(ctx, reason)
+    }
+
+    /// Resume distributions and clear the pause reason (policy authority only)
+    pub fn unpause_vault(ctx: Context<UnpauseVault>) -> Result<()> {
+        instructions::unpause_vault_handler(ctx)
+    }
+
+    /// Create the vault index page the next initialized vault is appended to (permissionless)
+    pub fn initialize_vault_index_page(ctx: Context<InitializeVaultIndexPage>) -> Result<()> {
+        instructions::initialize_vault_index_page_handler(ctx)
+    }
+
+    /// Pay out creator remainders accrued under deferred payouts (creator only)
+    pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+        instructions::claim_creator_fees_handler(ctx)
+    }
+
+    /// Grow a vault's policy and progress accounts to the current layouts (upgrade authority only)
+    pub fn upgrade_state(ctx: Context<UpgradeState>) -> Result<()> {
+        instructions::upgrade_state_handler(ctx)
     }
 }
@@ -1,8 +1,196 @@
 use anchor_lang::prelude::*;
+use crate::utils::MathUtil;
+
+/// Where swept dust is sent
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DustDestination {
+    /// Transfer dust to the creator's quote ATA
+    Creator,
+    /// Add dust to the next day's carry-over
+    NextDayCarry,
+    /// Burn dust from the treasury
+    Burn,
+}
+
+/// Order in which the day's fees are split between investors and the creator
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DistributionOrder {
+    /// Investors take their locked-weighted share first; the creator gets the remainder
+    InvestorsFirst,
+    /// The creator's `10000 - investor_fee_share_bps` cut comes off the top; investors split the rest by locked fraction
+    CreatorFirst,
+}
+
+impl DistributionOrder {
+    /// Share of the day's fees weighted by the locked fraction, in basis points
+    ///
+    /// Investors first: `min(investor_fee_share_bps, f_locked)` of the whole day. Creator
+    /// first: `f_locked` of what is left after the creator's cut.
+    pub fn eligible_share_bps(
+        self,
+        total_locked: u64,
+        total_investor_allocation: u64,
+        investor_fee_share_bps: u16,
+    ) -> Result<u16> {
+        let max_share_bps = match self {
+            DistributionOrder::InvestorsFirst => investor_fee_share_bps,
+            DistributionOrder::CreatorFirst => 10000,
+        };
+        MathUtil::calculate_eligible_share_bps(total_locked, total_investor_allocation, max_share_bps)
+    }
+    
+    /// Investor allocation still available today, before the daily cap
+    ///
+    /// `total_available` is the day's full amount and `already_distributed` what investors
+    /// received from it so far; the shares are the ones fixed for the day.
+    pub fn investor_allocation(
+        self,
+        investor_fee_share_bps: u16,
+        eligible_share_bps: u16,
+        total_available: u64,
+        already_distributed: u64,
+    ) -> Result<u64> {
+        match self {
+            DistributionOrder::InvestorsFirst => {
+                MathUtil::apply_bps(
+                    MathUtil::safe_sub(total_available, already_distributed)?,
+                    eligible_share_bps as u64
+                )
+            }
+            DistributionOrder::CreatorFirst => {
+                // The creator's cut is set aside before the locked fraction is applied
+                let creator_cut = MathUtil::apply_bps(total_available, 10000 - investor_fee_share_bps as u64)?;
+                let investor_pool = MathUtil::safe_sub(total_available, creator_cut)?
+                    .saturating_sub(already_distributed);
+                MathUtil::apply_bps(investor_pool, eligible_share_bps as u64)
+            }
+        }
+    }
+}
+
+/// How rounding residue of pro-rata payouts is handled
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Floor every payout; the residue stays in the creator remainder
+    Floor,
+    /// Hand the residue out one unit at a time to the largest fractional remainders
+    LargestRemainder,
+}
+
+/// Where the rounding residue left by floored investor payouts goes at day close
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResidueDestination {
+    /// Leave the residue in the creator remainder
+    Creator,
+    /// Carry the residue into the next day's investor distribution
+    CarryOver,
+    /// Hold the residue in the treasury's dust ledger until swept
+    DustLedger,
+}
+
+/// How the minimum payout threshold is expressed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MinPayoutMode {
+    /// A fixed amount of quote tokens (`min_payout_lamports`)
+    Fixed,
+    /// Basis points of the day's investor allocation (`min_payout_bps`)
+    AllocationBps,
+}
+
+/// Why distributions are paused
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PauseReason {
+    /// The position's claim returned base-token fees
+    BaseFeesDetected,
+    /// Paused by the authority or guardian for operational reasons
+    AdminPause,
+    /// An automated or off-chain circuit breaker tripped
+    CircuitBreaker,
+    /// Set by the retired price-range monitor; DAMM v2 positions have no tick range
+    PositionRangeBreached,
+}
+
+/// One point of the investor fee-share schedule
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeSharePoint {
+    /// Unix timestamp at which the share equals `bps`
+    pub timestamp: i64,
+    
+    /// Investor fee share in basis points (0-10000)
+    pub bps: u16,
+}
+
+impl FeeSharePoint {
+    pub const LEN: usize = 8 + 2;
+}
+
+/// Program-wide bounds on the policy parameters a vault may be initialized with
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ParameterBounds {
+    /// Largest investor fee share in basis points, including schedule points
+    pub max_investor_fee_share_bps: u16,
+    
+    /// Largest `max_page_size` a vault may allow
+    pub max_page_size: u32,
+    
+    /// Largest `max_investors` a vault may allow
+    pub max_investors: u32,
+    
+    /// Longest creator vesting period in seconds
+    pub max_creator_vesting_period: i64,
+    
+    /// Shortest distribution period in seconds
+    pub min_distribution_period: i64,
+    
+    /// Longest distribution period in seconds
+    pub max_distribution_period: i64,
+}
+
+impl ParameterBounds {
+    pub const LEN: usize = 2 + 4 + 4 + 8 + 8 + 8;
+    
+    /// Bounds must themselves be satisfiable by some valid policy
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.max_investor_fee_share_bps <= 10000
+                && self.max_page_size > 0
+                && self.max_investors > 0
+                && self.max_investors as u64 <= MAX_INVESTORS_PER_DAY
+                && self.max_creator_vesting_period >= 0
+                && MIN_DISTRIBUTION_PERIOD <= self.min_distribution_period
+                && self.min_distribution_period <= self.max_distribution_period
+                && self.max_distribution_period <= MAX_DISTRIBUTION_PERIOD,
+            crate::error::HonoraryFeeError::InvalidGlobalConfig
+        );
+        Ok(())
+    }
+}
+
+/// Commitment to the day's investor set: Merkle root of the ordered (stream, ata, locked) entries
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[derive(bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct InvestorSetCommitment {
+    /// Merkle root of the investor leaves
+    pub root: [u8; 32],
+    
+    /// Number of leaves
+    pub investor_count: u64,
+    
+    /// Sum of the committed locked amounts
+    pub total_locked: u64,
+}
+
+impl InvestorSetCommitment {
+    pub const LEN: usize = 32 + 8 + 8;
+}
 
 /// State structure for the policy configuration
 #[account]
 pub struct PolicyState {
+    /// Account layout version
+    pub version: u8,
+    
     /// Fee share for investors in basis points (0-10000)
     pub investor_fee_share_bps: u16,
     
@@ -12,74 +200,1202 @@ pub struct PolicyState {
     /// Minimum payout threshold in lamports
     pub min_payout_lamports: u64,
     
+    /// Whether the minimum payout is `min_payout_lamports` or `min_payout_bps` of the day's allocation
+    pub min_payout_mode: MinPayoutMode,
+    
+    /// Minimum payout in basis points of the day's investor allocation (AllocationBps mode)
+    pub min_payout_bps: u16,
+    
+    /// cp-amm pool the honorary position provides liquidity to
+    pub pool: Pubkey,
+    
+    /// Honorary cp-amm position owned by the position owner PDA
+    pub position: Pubkey,
+    
     /// The quote mint for this policy
     pub quote_mint: Pubkey,
     
+    /// The pool's other (base) mint, which the honorary position must never accrue
+    pub base_mint: Pubkey,
+    
     /// Creator's quote ATA for remainder distribution
     pub creator_quote_ata: Pubkey,
     
+    /// Second quote mint distributed alongside `quote_mint` (default when single-quote)
+    pub secondary_quote_mint: Pubkey,
+    
+    /// Creator's ATA for the secondary quote mint
+    pub secondary_creator_quote_ata: Pubkey,
+    
+    /// Decimals of `quote_mint`
+    pub quote_decimals: u8,
+    
+    /// Decimals of `secondary_quote_mint` (0 when single-quote)
+    pub secondary_quote_decimals: u8,
+    
     /// Total investor allocation minted at TGE (Y0)
     pub total_investor_allocation: u64,
     
+    /// Destination for swept dust
+    pub dust_destination: DustDestination,
+    
+    /// Account that paid the initialization deposit (refunded at sunset)
+    pub depositor: Pubkey,
+    
+    /// Authority allowed to perform administrative actions on this vault
+    pub authority: Pubkey,
+    
+    /// Proposed successor of `authority`, set until it accepts (default = none)
+    pub pending_authority: Pubkey,
+    
+    /// Key allowed to use the vault's emergency controls alongside the authority
+    pub guardian: Pubkey,
+    
+    /// Refundable anti-spam deposit held by this policy PDA
+    pub init_deposit_lamports: u64,
+    
+    /// Whether the vault has been sunset
+    pub sunset: bool,
+    
+    /// Why cranking is paused (None = not paused)
+    pub pause_reason: Option<PauseReason>,
+    
+    /// Seconds over which each day's creator remainder vests (0 = paid instantly at day close)
+    pub creator_vesting_period: i64,
+    
+    /// Whether creator remainders accrue in `creator_pending` and are paid by `claim_creator_fees`
+    pub defer_creator_payouts: bool,
+    
+    /// Whether the creator's share for the investors covered is paid with every page
+    pub creator_paid_per_page: bool,
+    
+    /// Smallest page size a keeper may request
+    pub min_page_size: u32,
+    
+    /// Largest page size a keeper may request
+    pub max_page_size: u32,
+    
+    /// Seconds that must pass between cranks within a day (0 = no throttle)
+    pub min_crank_interval_seconds: u32,
+    
+    /// Whether distributions use the investor registry and its locked-amount snapshot
+    pub registry_enabled: bool,
+    
+    /// Whether each day's investor set is committed as a Merkle root and pages carry proofs
+    pub merkle_investor_set: bool,
+    
+    /// Whether investors claim the day's allocation with Merkle proofs instead of being paid in pages
+    pub pull_claims: bool,
+    
+    /// Whether every investor entry carries a payout record slot
+    pub payout_records: bool,
+    
+    /// Whether every investor entry carries a day-scoped payout receipt slot
+    pub payout_receipts: bool,
+    
+    /// Whether investor share clipped by the daily cap carries over instead of going to the creator
+    pub carry_capped_excess: bool,
+    
+    /// Whether the investor or the creator share is taken first
+    pub distribution_order: DistributionOrder,
+    
+    /// How rounding residue of pro-rata payouts is handled
+    pub rounding_mode: RoundingMode,
+    
+    /// Investor fee-share schedule, interpolated at day start (only the first `fee_share_schedule_len` points are used)
+    pub fee_share_schedule: [FeeSharePoint; MAX_FEE_SHARE_POINTS],
+    
+    /// Number of points in the fee-share schedule (0 = use `investor_fee_share_bps`)
+    pub fee_share_schedule_len: u8,
+    
+    /// Seconds after 00:00 UTC at which each day starts (None = 24h after the last day start)
+    pub day_anchor_offset: Option<i64>,
+    
+    /// Largest number of investors a day or the registry may hold
+    pub max_investors: u32,
+    
+    /// Canonical bump of the position owner PDA, used for signer seeds
+    pub position_owner_bump: u8,
+    
     /// Bump for PDA derivation
     pub bump: u8,
+    
+    /// cp-amm program the vault's pool and position belong to; every crank must pass it
+    pub cp_amm_program: Pubkey,
+    
+    /// Locker program the vault's streams are read from; every crank must pass it
+    pub streamflow_program: Pubkey,
+    
+    /// Formerly a synthetic tick range for the honorary position; unused, kept for the account layout
+    pub retired_tick_range: [u8; 8],
+    
+    /// Sender of the vault's investor streams (default = not checked, for vaults upgraded without it)
+    pub stream_sender: Pubkey,
+    
+    /// Where the day's rounding residue is routed at day close
+    pub residue_destination: ResidueDestination,
+    
+    /// Canonical bump of the quote treasury ATA, used to verify it without a runtime search
+    pub treasury_bump: u8,
+    
+    /// Canonical bump of the secondary quote treasury ATA (0 until a secondary quote is enabled)
+    pub secondary_treasury_bump: u8,
+    
+    /// Seconds between day starts when days are not anchored
+    pub distribution_period: i64,
+    
+    /// Zeroed space for fields added after launch
+    pub reserved: [u8; STATE_RESERVED_BYTES],
 }
 
 impl PolicyState {
+    /// Current account layout version
+    pub const VERSION: u8 = 7;
+    
     pub const LEN: usize = 8 + // discriminator
+        1 +    // version
         2 +    // investor_fee_share_bps
         8 +    // daily_cap
         8 +    // min_payout_lamports
+        1 +    // min_payout_mode
+        2 +    // min_payout_bps
+        32 +   // pool
+        32 +   // position
         32 +   // quote_mint
+        32 +   // base_mint
         32 +   // creator_quote_ata
+        32 +   // secondary_quote_mint
+        32 +   // secondary_creator_quote_ata
+        1 +    // quote_decimals
+        1 +    // secondary_quote_decimals
         8 +    // total_investor_allocation
-        1;     // bump
+        1 +    // dust_destination
+        32 +   // depositor
+        32 +   // authority
+        32 +   // pending_authority
+        32 +   // guardian
+        8 +    // init_deposit_lamports
+        1 +    // sunset
+        1 + 1 + // pause_reason
+        8 +    // creator_vesting_period
+        1 +    // defer_creator_payouts
+        1 +    // creator_paid_per_page
+        4 +    // min_page_size
+        4 +    // max_page_size
+        4 +    // min_crank_interval_seconds
+        1 +    // registry_enabled
+        1 +    // merkle_investor_set
+        1 +    // pull_claims
+        1 +    // payout_records
+        1 +    // payout_receipts
+        1 +    // carry_capped_excess
+        1 +    // distribution_order
+        1 +    // rounding_mode
+        FeeSharePoint::LEN * MAX_FEE_SHARE_POINTS + // fee_share_schedule
+        1 +    // fee_share_schedule_len
+        1 + 8 + // day_anchor_offset
+        4 +    // max_investors
+        1 +    // position_owner_bump
+        1 +    // bump
+        32 +   // cp_amm_program
+        32 +   // streamflow_program
+        8 +    // retired_tick_range
+        32 +   // stream_sender
+        1 +    // residue_destination
+        1 +    // treasury_bump
+        1 +    // secondary_treasury_bump
+        8 +    // distribution_period
+        STATE_RESERVED_BYTES; // reserved
+    
+    /// Investor fee share in effect at `ts`
+    ///
+    /// Linearly interpolates between the surrounding schedule points and holds the
+    /// first/last point outside the schedule; falls back to `investor_fee_share_bps`
+    /// when no schedule is set.
+    pub fn investor_fee_share_bps_at(&self, ts: i64) -> u16 {
+        let len = (self.fee_share_schedule_len as usize).min(MAX_FEE_SHARE_POINTS);
+        let points = &self.fee_share_schedule[..len];
+        let (first, last) = match (points.first(), points.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return self.investor_fee_share_bps,
+        };
+        if ts <= first.timestamp {
+            return first.bps;
+        }
+        if ts >= last.timestamp {
+            return last.bps;
+        }
+        
+        for window in points.windows(2) {
+            let (start, end) = (window[0], window[1]);
+            if ts < end.timestamp {
+                let elapsed = (ts - start.timestamp) as i128;
+                let span = (end.timestamp - start.timestamp) as i128;
+                let delta = end.bps as i128 - start.bps as i128;
+                return (start.bps as i128 + delta * elapsed / span) as u16;
+            }
+        }
+        last.bps
+    }
+    
+    /// Check that a nonzero daily cap leaves room for payouts above a fixed minimum
+    ///
+    /// A cap below `MIN_PAYOUTS_PER_DAILY_CAP` minimum payouts would withhold most or all
+    /// payouts as dust and send the day's fees to the creator. Shared by every
+    /// instruction that sets either parameter.
+    pub fn validate_cap_and_min_payout(
+        daily_cap: u64,
+        min_payout_mode: MinPayoutMode,
+        min_payout_lamports: u64,
+    ) -> Result<()> {
+        if daily_cap == 0 || min_payout_mode != MinPayoutMode::Fixed {
+            return Ok(());
+        }
+        require!(
+            daily_cap as u128 >= min_payout_lamports as u128 * MIN_PAYOUTS_PER_DAILY_CAP as u128,
+            crate::error::HonoraryFeeError::MinPayoutExceedsCap
+        );
+        Ok(())
+    }
+    
+    /// Whether a stream created by `sender` may belong to this vault's investor cohort
+    pub fn is_vault_stream_sender(&self, sender: &Pubkey) -> bool {
+        self.stream_sender == Pubkey::default() || self.stream_sender == *sender
+    }
+    
+    /// Whether a new day may start at `current_ts` after one started at `last_ts`
+    pub fn is_day_elapsed(&self, last_ts: i64, current_ts: i64) -> bool {
+        match self.day_anchor_offset {
+            Some(offset) => MathUtil::is_anchored_day_elapsed(last_ts, current_ts, offset),
+            None => MathUtil::is_period_elapsed(last_ts, current_ts, self.distribution_period),
+        }
+    }
+    
+    /// Number of investors the registry and its snapshot are sized for
+    pub fn registry_capacity(&self) -> usize {
+        (self.max_investors as usize).min(MAX_REGISTERED_INVESTORS)
+    }
+    
+    /// Whether a second quote mint has been enabled
+    pub fn has_secondary_quote(&self) -> bool {
+        self.secondary_quote_mint != Pubkey::default()
+    }
+    
+    /// Whether `mint` is the enabled secondary quote mint
+    pub fn is_secondary_quote(&self, mint: &Pubkey) -> bool {
+        self.has_secondary_quote() && *mint == self.secondary_quote_mint
+    }
+    
+    /// Whether fees in `mint` are distributed by this vault
+    pub fn is_quote_mint(&self, mint: &Pubkey) -> bool {
+        *mint == self.quote_mint || self.is_secondary_quote(mint)
+    }
+    
+    /// Creator ATA receiving the remainder in `mint`
+    pub fn creator_quote_ata_for(&self, mint: &Pubkey) -> Pubkey {
+        if self.is_secondary_quote(mint) {
+            self.secondary_creator_quote_ata
+        } else {
+            self.creator_quote_ata
+        }
+    }
+    
+    /// Largest page `distribute_fees` can process within `PAGE_INVESTOR_COMPUTE_BUDGET`
+    ///
+    /// The per-investor cost grows with every optional account the vault's mode adds.
+    pub fn max_investors_per_page(&self, create_missing_atas: bool) -> u32 {
+        (PAGE_INVESTOR_COMPUTE_BUDGET / self.investor_compute_units(create_missing_atas)) as u32
+    }
+    
+    /// Compute units of a page paying `page_size` investors while reading `investors_read`
+    ///
+    /// Investors read beyond the page (stream vaults pass the whole list to recount the
+    /// day) are parsed but not paid, and cost `INVESTOR_PARSE_COMPUTE_UNITS` each.
+    pub fn page_compute_units(&self, page_size: u32, investors_read: u64, create_missing_atas: bool) -> u64 {
+        let unpaid = investors_read.saturating_sub(page_size as u64);
+        (page_size as u64)
+            .saturating_mul(self.investor_compute_units(create_missing_atas))
+            .saturating_add(unpaid.saturating_mul(INVESTOR_PARSE_COMPUTE_UNITS))
+    }
+    
+    /// Compute cost of one paid investor in this vault's mode
+    fn investor_compute_units(&self, create_missing_atas: bool) -> u64 {
+        let mut per_investor = INVESTOR_COMPUTE_UNITS;
+        if self.payout_receipts {
+            per_investor += PAYOUT_RECEIPT_COMPUTE_UNITS;
+        }
+        if self.payout_records {
+            per_investor += PAYOUT_RECORD_COMPUTE_UNITS;
+        }
+        if self.merkle_investor_set {
+            per_investor += MERKLE_PROOF_COMPUTE_UNITS;
+        }
+        if create_missing_atas {
+            per_investor += ATA_CREATION_COMPUTE_UNITS;
+        }
+        per_investor
+    }
+    
+    /// Canonical bump of the treasury ATA holding `mint`
+    pub fn treasury_bump_for(&self, mint: &Pubkey) -> u8 {
+        if self.is_secondary_quote(mint) {
+            self.secondary_treasury_bump
+        } else {
+            self.treasury_bump
+        }
+    }
+    
+    /// Decimals of the quote mint `mint`
+    pub fn quote_decimals_for(&self, mint: &Pubkey) -> u8 {
+        if self.is_secondary_quote(mint) {
+            self.secondary_quote_decimals
+        } else {
+            self.quote_decimals
+        }
+    }
+    
+    /// Seed of the progress account tracking distributions in `mint`
+    pub fn progress_seed_for(&self, mint: &Pubkey) -> &'static [u8] {
+        if self.is_secondary_quote(mint) {
+            SECONDARY_PROGRESS_SEED
+        } else {
+            PROGRESS_SEED
+        }
+    }
+    
+    /// Seed of the progress account tracking the quote mint other than `mint`
+    pub fn paired_progress_seed_for(&self, mint: &Pubkey) -> &'static [u8] {
+        if self.is_secondary_quote(mint) {
+            PROGRESS_SEED
+        } else {
+            SECONDARY_PROGRESS_SEED
+        }
+    }
+    
+    /// The quote mint other than `mint`, when a secondary quote mint is enabled
+    pub fn paired_quote_mint(&self, mint: &Pubkey) -> Option<Pubkey> {
+        if !self.has_secondary_quote() {
+            None
+        } else if *mint == self.secondary_quote_mint {
+            Some(self.quote_mint)
+        } else {
+            Some(self.secondary_quote_mint)
+        }
+    }
 }
 
 /// State structure for tracking distribution progress
-#[account]
+///
+/// Zero-copy so the per-day processed bitmap can be read and updated in place.
+/// Fields are ordered so the `repr(C)` layout has no implicit padding.
+#[account(zero_copy)]
 pub struct ProgressState {
+    /// Account layout version
+    pub version: u8,
+    
+    /// Whether the current day's distribution is complete (0 or 1)
+    pub day_complete: u8,
+    
+    /// Whether the creator acknowledged the current creator-only streak (0 or 1)
+    pub creator_only_acknowledged: u8,
+    
+    /// Bump for PDA derivation
+    pub bump: u8,
+    
+    /// Consecutive closed days with no locked investor weight (all fees went to the creator)
+    pub creator_only_streak: u16,
+    
+    /// Investor fee share in effect for the current day, fixed at day start
+    pub investor_fee_share_bps: u16,
+    
+    /// Locked-weighted share of the current day, fixed by the day's first page
+    pub eligible_share_bps: u16,
+    
+    /// Set while a day-start crank claims fees (0 or 1); any crank observing it is rejected
+    pub day_starting: u8,
+    
+    /// Whether the current day's fees were claimed (0 or 1); cleared only when the day closes
+    pub claimed_this_day: u8,
+    
+    /// Error code (Anchor numbering) of the last investor payout that could not be made (0 = none)
+    pub last_error_code: u32,
+    
     /// Last distribution timestamp
     pub last_distribution_ts: i64,
     
+    /// Monotonic identifier of the current day, incremented at each day start
+    pub day_id: u64,
+    
+    /// Timestamp of the last distribute_fees crank
+    pub last_crank_ts: i64,
+    
+    /// distribute_fees cranks since the current day started (including the day-start crank)
+    pub cranks_this_day: u64,
+    
+    /// Number of events emitted against this progress account; each event carries the incremented value
+    pub event_seq: u64,
+    
+    /// Payer of the last distribute_fees crank
+    pub last_crank_caller: Pubkey,
+    
     /// Total distributed today
     pub daily_distributed: u64,
     
+    /// Creator share paid with today's pages ahead of day close
+    pub creator_paid_today: u64,
+    
+    /// Lifetime count of investor payouts skipped because of an invalid stream or ATA
+    pub failed_transfer_count: u64,
+    
     /// Carry-over amount from previous distributions
     pub carry_over: u64,
     
-    /// Current pagination cursor
+    /// Lowest investor index not yet processed today
     pub pagination_cursor: u64,
     
+    /// Investors processed today
+    pub processed_count: u64,
+    
+    /// Size of the investor set committed by the day-start crank
+    pub expected_investor_count: u64,
+    
+    /// Locked amount across the day's investors, fixed by the first page and used by every page
+    pub total_locked_today: u64,
+    
+    /// Daily investor cap in effect for the current day, fixed at day start (0 = no cap)
+    pub daily_cap: u64,
+    
+    /// Minimum investor payout in effect for the current day, fixed by the day's first page
+    pub min_payout: u64,
+    
+    /// Treasury balance the program accounts for: set after the day-start claim and moved with every tracked transfer
+    pub treasury_checkpoint: u64,
+    
     /// Current day's total claimed fees before distribution
     pub daily_claimed_total: u64,
     
-    /// Whether the current day's distribution is complete
-    pub day_complete: bool,
+    /// Base-token fees the current day's claim returned, quarantined in the base treasury
+    pub daily_claimed_base: u64,
+    
+    /// Fees in this mint claimed by the other quote mint's day-start crank (applied at the next day start)
+    pub pending_claimed: u64,
+    
+    /// Sub-threshold payouts withheld today
+    pub daily_dust: u64,
+    
+    /// Rounding residue left by today's floored investor payouts
+    pub daily_residue: u64,
+    
+    /// Unswept dust held in the treasury
+    pub dust_ledger: u64,
+    
+    /// Dust held in the treasury for investors with a payout record, paid with their next payout
+    pub investor_dust_owed: u64,
+    
+    /// Donations received since the current day started (applied at the next day start)
+    pub pending_donations: u64,
+    
+    /// Donations included in the current day's distribution
+    pub daily_donations: u64,
+    
+    /// Lifetime donations received
+    pub total_donations: u64,
+    
+    /// Escrowed creator remainder released and claimable
+    pub creator_escrow_vested: u64,
+    
+    /// Escrowed creator remainder still vesting
+    pub creator_escrow_unvested: u64,
+    
+    /// Timestamp the unvested escrow was last checkpointed
+    pub creator_escrow_last_ts: i64,
+    
+    /// Timestamp at which the unvested escrow is fully released
+    pub creator_escrow_end_ts: i64,
+    
+    /// Creator remainders accrued under deferred payouts and not yet claimed
+    pub creator_pending: u64,
+    
+    /// Allocations finalized for pull claims but not yet claimed
+    pub claims_outstanding: u64,
+    
+    /// Investor set committed at the current day's start (Merkle mode)
+    pub investor_set: InvestorSetCommitment,
+    
+    /// Investor indices processed today, one bit per index
+    pub processed_bitmap: [u8; PROCESSED_BITMAP_BYTES],
+    
+    /// Zeroed space for fields added after launch
+    pub reserved: [u8; STATE_RESERVED_BYTES],
+}
+
+impl ProgressState {
+    /// Current account layout version
+    pub const VERSION: u8 = 2;
+    
+    pub const LEN: usize = 8 + std::mem::size_of::<ProgressState>();
+    
+    pub fn is_day_complete(&self) -> bool {
+        self.day_complete != 0
+    }
+    
+    pub fn set_day_complete(&mut self, complete: bool) {
+        self.day_complete = complete as u8;
+    }
+    
+    pub fn is_day_starting(&self) -> bool {
+        self.day_starting != 0
+    }
+    
+    /// Enter the day-start section; fails if a day start is already under way
+    pub fn begin_day_start(&mut self) -> Result<()> {
+        require!(!self.is_day_starting(), crate::error::HonoraryFeeError::DayStartInProgress);
+        self.day_starting = 1;
+        Ok(())
+    }
+    
+    pub fn end_day_start(&mut self) {
+        self.day_starting = 0;
+    }
+    
+    pub fn is_claimed_this_day(&self) -> bool {
+        self.claimed_this_day != 0
+    }
+    
+    pub fn set_claimed_this_day(&mut self, claimed: bool) {
+        self.claimed_this_day = claimed as u8;
+    }
+    
+    /// Record the day's fee claim, on top of any claimed earlier by the other mint's crank
+    ///
+    /// A day claims once: a second claim before the day closes fails with `DayAlreadyClaimed`.
+    /// Returns whether the claim returned base fees, which must be quarantined.
+    pub fn record_claim(&mut self, claimed: u64, base_claimed: u64) -> Result<bool> {
+        require!(!self.is_claimed_this_day(), crate::error::HonoraryFeeError::DayAlreadyClaimed);
+        self.daily_claimed_total = crate::utils::MathUtil::safe_add(self.daily_claimed_total, claimed)?;
+        self.daily_claimed_base = crate::utils::MathUtil::safe_add(self.daily_claimed_base, base_claimed)?;
+        self.set_claimed_this_day(true);
+        Ok(base_claimed > 0)
+    }
+    
+    pub fn is_creator_only_acknowledged(&self) -> bool {
+        self.creator_only_acknowledged != 0
+    }
+    
+    pub fn set_creator_only_acknowledged(&mut self, acknowledged: bool) {
+        self.creator_only_acknowledged = acknowledged as u8;
+    }
+    
+    /// Whether the investor at `index` was already processed today
+    pub fn is_processed(&self, index: u64) -> bool {
+        match self.processed_bitmap.get((index / 8) as usize) {
+            Some(byte) => byte & (1 << (index % 8)) != 0,
+            None => false,
+        }
+    }
+    
+    /// Mark the investor at `index` as processed and advance the cursor past processed indices
+    pub fn mark_processed(&mut self, index: u64) -> Result<()> {
+        require!(
+            index < MAX_INVESTORS_PER_DAY,
+            crate::error::HonoraryFeeError::TooManyInvestors
+        );
+        if self.is_processed(index) {
+            return Ok(());
+        }
+        
+        self.processed_bitmap[(index / 8) as usize] |= 1 << (index % 8);
+        self.processed_count = crate::utils::MathUtil::safe_add(self.processed_count, 1)?;
+        while self.is_processed(self.pagination_cursor) {
+            self.pagination_cursor += 1;
+        }
+        Ok(())
+    }
+    
+    /// Record a distribute_fees crank by `caller`, restarting the count when a day starts
+    pub fn record_crank(&mut self, caller: Pubkey, current_ts: i64, starts_day: bool) -> Result<()> {
+        if starts_day {
+            self.cranks_this_day = 0;
+        }
+        self.cranks_this_day = crate::utils::MathUtil::safe_add(self.cranks_this_day, 1)?;
+        self.last_crank_ts = current_ts;
+        self.last_crank_caller = caller;
+        Ok(())
+    }
+    
+    /// Check a tracked treasury outflow against the observed balances and move the checkpoint
+    ///
+    /// The balance must drop by exactly `outflow` (`TreasuryDeltaMismatch` otherwise), and
+    /// never below the checkpoint; tokens sent to the treasury outside the program are
+    /// tolerated as surplus.
+    pub fn record_treasury_outflow(&mut self, balance_before: u64, balance_after: u64, outflow: u64) -> Result<()> {
+        if balance_before.checked_sub(balance_after) != Some(outflow) {
+            msg!("Treasury moved from {} to {}, expected an outflow of {}", balance_before, balance_after, outflow);
+            return err!(crate::error::HonoraryFeeError::TreasuryDeltaMismatch);
+        }
+        self.treasury_checkpoint = self.treasury_checkpoint
+            .checked_sub(outflow)
+            .ok_or(crate::error::HonoraryFeeError::InvariantViolation)?;
+        require!(
+            balance_after >= self.treasury_checkpoint,
+            crate::error::HonoraryFeeError::InvariantViolation
+        );
+        Ok(())
+    }
+    
+    /// Take the next event sequence number
+    pub fn next_event_seq(&mut self) -> u64 {
+        self.event_seq = self.event_seq.wrapping_add(1);
+        self.event_seq
+    }
+    
+    /// Clear the processed bitmap for a new day
+    pub fn reset_processed(&mut self) {
+        self.processed_bitmap = [0; PROCESSED_BITMAP_BYTES];
+        self.processed_count = 0;
+        self.pagination_cursor = 0;
+    }
+    
+    /// Claimed fees plus carry-over and donations available to the current day
+    pub fn total_available(&self) -> Result<u64> {
+        crate::utils::MathUtil::safe_add(
+            crate::utils::MathUtil::safe_add(self.daily_claimed_total, self.carry_over)?,
+            self.daily_donations,
+        )
+    }
+    
+    /// Amount of the current day not yet paid to investors
+    pub fn remaining_for_distribution(&self) -> Result<u64> {
+        crate::utils::MathUtil::safe_sub(self.total_available()?, self.daily_distributed)
+    }
+    
+    /// Part of the current day the treasury must still hold: everything not yet paid to
+    /// investors or, with per-page creator payouts, to the creator
+    pub fn day_treasury_obligation(&self) -> Result<u64> {
+        crate::utils::MathUtil::safe_sub(self.remaining_for_distribution()?, self.creator_paid_today)
+    }
+    
+    /// Release the linearly vested part of the creator escrow up to `current_ts`
+    pub fn checkpoint_creator_escrow(&mut self, current_ts: i64) -> Result<()> {
+        use crate::utils::MathUtil;
+        
+        if self.creator_escrow_unvested == 0 || current_ts <= self.creator_escrow_last_ts {
+            return Ok(());
+        }
+        
+        let released = if current_ts >= self.creator_escrow_end_ts {
+            self.creator_escrow_unvested
+        } else {
+            let elapsed = (current_ts - self.creator_escrow_last_ts) as u64;
+            let remaining = (self.creator_escrow_end_ts - self.creator_escrow_last_ts) as u64;
+            (self.creator_escrow_unvested as u128 * elapsed as u128 / remaining as u128) as u64
+        };
+        
+        self.creator_escrow_vested = MathUtil::safe_add(self.creator_escrow_vested, released)?;
+        self.creator_escrow_unvested = MathUtil::safe_sub(self.creator_escrow_unvested, released)?;
+        self.creator_escrow_last_ts = current_ts;
+        Ok(())
+    }
+    
+    /// Add a creator remainder to the escrow, vesting everything unreleased over `vesting_period`
+    pub fn accrue_creator_escrow(&mut self, amount: u64, current_ts: i64, vesting_period: i64) -> Result<()> {
+        self.checkpoint_creator_escrow(current_ts)?;
+        self.creator_escrow_unvested =
+            crate::utils::MathUtil::safe_add(self.creator_escrow_unvested, amount)?;
+        self.creator_escrow_last_ts = current_ts;
+        self.creator_escrow_end_ts = current_ts
+            .checked_add(vesting_period)
+            .ok_or(crate::error::HonoraryFeeError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+/// Program-wide configuration
+#[account]
+pub struct GlobalConfig {
+    /// Program admin allowed to update the configuration
+    pub admin: Pubkey,
+    
+    /// Refundable lamport deposit required to initialize a vault
+    pub init_deposit_lamports: u64,
+    
+    /// cp-amm program IDs vaults may be initialized against
+    pub allowed_cp_amm_programs: [Pubkey; MAX_ALLOWED_PROGRAMS],
+    
+    /// Number of valid entries in `allowed_cp_amm_programs`
+    pub allowed_cp_amm_programs_len: u8,
+    
+    /// Locker programs investor streams may be owned by
+    pub allowed_locker_programs: [Pubkey; MAX_ALLOWED_PROGRAMS],
+    
+    /// Number of valid entries in `allowed_locker_programs`
+    pub allowed_locker_programs_len: u8,
+    
+    /// Bounds on the policy parameters of new vaults
+    pub bounds: ParameterBounds,
+    
+    /// Number of vaults initialized so far; the next vault's position in the vault index
+    pub vault_count: u64,
     
     /// Bump for PDA derivation
     pub bump: u8,
 }
 
-impl ProgressState {
+impl GlobalConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 +   // admin
+        8 +    // init_deposit_lamports
+        32 * MAX_ALLOWED_PROGRAMS + // allowed_cp_amm_programs
+        1 +    // allowed_cp_amm_programs_len
+        32 * MAX_ALLOWED_PROGRAMS + // allowed_locker_programs
+        1 +    // allowed_locker_programs_len
+        ParameterBounds::LEN + // bounds
+        8 +    // vault_count
+        1;     // bump
+    
+    /// Index page the next initialized vault is appended to
+    pub fn current_index_page(&self) -> u32 {
+        (self.vault_count / VAULT_INDEX_PAGE_CAPACITY as u64) as u32
+    }
+    
+    /// Replace both program allow-lists; each must be non-empty and fit the fixed arrays
+    pub fn set_allowed_programs(
+        &mut self,
+        cp_amm_programs: &[Pubkey],
+        locker_programs: &[Pubkey],
+    ) -> Result<()> {
+        require!(
+            !cp_amm_programs.is_empty()
+                && cp_amm_programs.len() <= MAX_ALLOWED_PROGRAMS
+                && !locker_programs.is_empty()
+                && locker_programs.len() <= MAX_ALLOWED_PROGRAMS,
+            crate::error::HonoraryFeeError::InvalidGlobalConfig
+        );
+        
+        self.allowed_cp_amm_programs = [Pubkey::default(); MAX_ALLOWED_PROGRAMS];
+        self.allowed_cp_amm_programs[..cp_amm_programs.len()].copy_from_slice(cp_amm_programs);
+        self.allowed_cp_amm_programs_len = cp_amm_programs.len() as u8;
+        self.allowed_locker_programs = [Pubkey::default(); MAX_ALLOWED_PROGRAMS];
+        self.allowed_locker_programs[..locker_programs.len()].copy_from_slice(locker_programs);
+        self.allowed_locker_programs_len = locker_programs.len() as u8;
+        Ok(())
+    }
+    
+    pub fn is_allowed_cp_amm_program(&self, program: &Pubkey) -> bool {
+        self.allowed_cp_amm_programs[..self.allowed_cp_amm_programs_len as usize].contains(program)
+    }
+    
+    pub fn is_allowed_locker_program(&self, program: &Pubkey) -> bool {
+        self.allowed_locker_programs[..self.allowed_locker_programs_len as usize].contains(program)
+    }
+}
+
+/// One page of the program-wide vault index, in initialization order
+#[account]
+pub struct VaultIndexPage {
+    /// Page number (vault `i` lives on page `i / VAULT_INDEX_PAGE_CAPACITY`)
+    pub page: u32,
+    
+    /// Vaults on this page
+    pub vaults: Vec<Pubkey>,
+    
+    /// Bump for PDA derivation
+    pub bump: u8,
+}
+
+impl VaultIndexPage {
+    pub const LEN: usize = 8 + // discriminator
+        4 +    // page
+        4 + 32 * VAULT_INDEX_PAGE_CAPACITY + // vaults
+        1;     // bump
+}
+
+/// Compact per-vault summary for dashboards, refreshed at day close
+#[account]
+pub struct VaultSummary {
+    /// Vault this summary belongs to
+    pub vault: Pubkey,
+    
+    /// Quote mint of the vault
+    pub quote_mint: Pubkey,
+    
+    /// Start timestamp of the last closed day
+    pub last_day_ts: i64,
+    
+    /// Quote fees claimed on the last closed day
+    pub last_claimed: u64,
+    
+    /// Amount paid to investors on the last closed day
+    pub last_investor_distributed: u64,
+    
+    /// Amount paid to the creator on the last closed day
+    pub last_creator_amount: u64,
+    
+    /// Current carry-over into the next day
+    pub carry_over: u64,
+    
+    /// Current unswept dust
+    pub dust_ledger: u64,
+    
+    /// Investor fee share cap in basis points
+    pub investor_fee_share_bps: u16,
+    
+    /// Bump for PDA derivation
+    pub bump: u8,
+}
+
+impl VaultSummary {
     pub const LEN: usize = 8 + // discriminator
-        8 +    // last_distribution_ts
-        8 +    // daily_distributed
+        32 +   // vault
+        32 +   // quote_mint
+        8 +    // last_day_ts
+        8 +    // last_claimed
+        8 +    // last_investor_distributed
+        8 +    // last_creator_amount
         8 +    // carry_over
-        8 +    // pagination_cursor
-        8 +    // daily_claimed_total
-        1 +    // day_complete
+        8 +    // dust_ledger
+        2 +    // investor_fee_share_bps
+        1;     // bump
+    
+    /// Refresh the summary from the vault state at day close
+    pub fn refresh(&mut self, policy: &PolicyState, progress: &ProgressState, creator_amount: u64) {
+        self.quote_mint = policy.quote_mint;
+        self.last_day_ts = progress.last_distribution_ts;
+        self.last_claimed = progress.daily_claimed_total;
+        self.last_investor_distributed = progress.daily_distributed;
+        self.last_creator_amount = creator_amount;
+        self.carry_over = progress.carry_over;
+        self.dust_ledger = progress.dust_ledger;
+        self.investor_fee_share_bps = progress.investor_fee_share_bps;
+    }
+}
+
+/// Lifetime totals for a vault, updated by the distribution crank
+#[account]
+pub struct VaultStats {
+    /// Vault these statistics belong to
+    pub vault: Pubkey,
+    
+    /// Quote fees claimed from the honorary position
+    pub total_fees_claimed: u64,
+    
+    /// Amount paid to investors
+    pub total_paid_to_investors: u64,
+    
+    /// Creator remainders, whether transferred or escrowed
+    pub total_paid_to_creator: u64,
+    
+    /// Sub-threshold payouts withheld as dust
+    pub total_dust: u64,
+    
+    /// Number of closed distribution days
+    pub distribution_days: u64,
+    
+    /// Bump for PDA derivation
+    pub bump: u8,
+}
+
+impl VaultStats {
+    pub const LEN: usize = 8 + // discriminator
+        32 +   // vault
+        8 +    // total_fees_claimed
+        8 +    // total_paid_to_investors
+        8 +    // total_paid_to_creator
+        8 +    // total_dust
+        8 +    // distribution_days
+        1;     // bump
+}
+
+/// Lifetime payouts received by one investor quote ATA
+#[account]
+pub struct InvestorPayoutRecord {
+    /// Vault paying the investor
+    pub vault: Pubkey,
+    
+    /// Investor quote token account the record tracks
+    pub investor_quote_ata: Pubkey,
+    
+    /// Total quote tokens received
+    pub total_received: u64,
+    
+    /// Number of payouts received
+    pub payout_count: u64,
+    
+    /// Timestamp of the last payout
+    pub last_payout_ts: i64,
+    
+    /// Sub-threshold payouts withheld so far, added to the next payout
+    pub pending_dust: u64,
+    
+    /// Bump for PDA derivation
+    pub bump: u8,
+}
+
+impl InvestorPayoutRecord {
+    pub const LEN: usize = 8 + // discriminator
+        32 +   // vault
+        32 +   // investor_quote_ata
+        8 +    // total_received
+        8 +    // payout_count
+        8 +    // last_payout_ts
+        8 +    // pending_dust
         1;     // bump
 }
 
+/// Marker that an investor stream was paid on a distribution day
+///
+/// Created on the investor's first payout of the day, so the same stream presented
+/// again later that day is skipped instead of paid twice.
+#[account]
+pub struct PayoutReceipt {
+    /// Vault that paid the investor
+    pub vault: Pubkey,
+    
+    /// Distribution day the receipt covers
+    pub day_id: u64,
+    
+    /// Investor stream that was paid
+    pub stream: Pubkey,
+    
+    /// Bump for PDA derivation
+    pub bump: u8,
+}
+
+impl PayoutReceipt {
+    pub const LEN: usize = 8 + // discriminator
+        32 +   // vault
+        8 +    // day_id
+        32 +   // stream
+        1;     // bump
+}
+
+/// Investor registered for distributions
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RegisteredInvestor {
+    /// Streamflow stream holding the investor's locked tokens
+    pub stream: Pubkey,
+    
+    /// Investor's quote token account receiving payouts
+    pub quote_ata: Pubkey,
+    
+    /// Weight multiplier applied to the locked amount (10000 = 1x)
+    pub multiplier_bps: u16,
+}
+
+/// Ordered list of the vault's investors; index order is the payout order
+#[account]
+pub struct InvestorRegistry {
+    /// Vault this registry belongs to
+    pub vault: Pubkey,
+    
+    /// Registered investors
+    pub investors: Vec<RegisteredInvestor>,
+    
+    /// Bump for PDA derivation
+    pub bump: u8,
+}
+
+impl InvestorRegistry {
+    pub const LEN: usize = Self::space(MAX_REGISTERED_INVESTORS);
+    
+    /// Account size for a registry holding up to `max_investors` investors
+    pub const fn space(max_investors: usize) -> usize {
+        8 + // discriminator
+        32 +   // vault
+        4 + max_investors * (32 + 32 + 2) + // investors
+        1      // bump
+    }
+}
+
+/// Locked amounts of every registered investor, used by the payout math for a whole day
+#[account]
+pub struct LockedSnapshot {
+    /// Vault this snapshot belongs to
+    pub vault: Pubkey,
+    
+    /// Start timestamp of the day that was last started when the snapshot was taken
+    pub base_day_ts: i64,
+    
+    /// Locked amount per registry index
+    pub locked: Vec<u64>,
+    
+    /// Sum of the recorded locked amounts
+    pub total_locked: u64,
+    
+    /// Bump for PDA derivation
+    pub bump: u8,
+}
+
+impl LockedSnapshot {
+    pub const LEN: usize = Self::space(MAX_REGISTERED_INVESTORS);
+    
+    /// Account size for a snapshot of up to `max_investors` investors
+    pub const fn space(max_investors: usize) -> usize {
+        8 + // discriminator
+        32 +   // vault
+        8 +    // base_day_ts
+        4 + max_investors * 8 + // locked
+        8 +    // total_locked
+        1      // bump
+    }
+    
+    /// Whether every registered investor has been recorded since the last day started
+    pub fn is_complete_for(&self, registry: &InvestorRegistry, progress: &ProgressState) -> bool {
+        self.base_day_ts == progress.last_distribution_ts
+            && self.locked.len() == registry.investors.len()
+    }
+}
+
+/// A day's finalized investor allocation, claimed individually with Merkle proofs
+#[account]
+pub struct ClaimDistribution {
+    /// Vault this distribution belongs to
+    pub vault: Pubkey,
+    
+    /// Start timestamp of the distribution day
+    pub day_ts: i64,
+    
+    /// Identifier of the distribution day
+    pub day_id: u64,
+    
+    /// Merkle root of the (index, quote_ata, amount) allocation leaves
+    pub root: [u8; 32],
+    
+    /// Number of allocation leaves
+    pub leaf_count: u64,
+    
+    /// Sum of all allocations
+    pub total_allocated: u64,
+    
+    /// Sum claimed so far
+    pub total_claimed: u64,
+    
+    /// One bit per leaf, set once claimed
+    pub claimed_bitmap: Vec<u8>,
+    
+    /// Bump for PDA derivation
+    pub bump: u8,
+}
+
+impl ClaimDistribution {
+    /// Account size for a distribution with `leaf_count` leaves
+    pub fn space(leaf_count: u64) -> usize {
+        8 +    // discriminator
+        32 +   // vault
+        8 +    // day_ts
+        8 +    // day_id
+        32 +   // root
+        8 +    // leaf_count
+        8 +    // total_allocated
+        8 +    // total_claimed
+        4 + (leaf_count as usize).div_ceil(8) + // claimed_bitmap
+        1      // bump
+    }
+    
+    pub fn is_claimed(&self, index: u64) -> bool {
+        self.claimed_bitmap[(index / 8) as usize] & (1 << (index % 8)) != 0
+    }
+    
+    pub fn set_claimed(&mut self, index: u64) {
+        self.claimed_bitmap[(index / 8) as usize] |= 1 << (index % 8);
+    }
+}
+
 /// Seeds for PDA derivation
+pub const GLOBAL_CONFIG_SEED: &[u8] = b"global_config";
 pub const VAULT_SEED: &[u8] = b"star_vault";
 pub const INVESTOR_FEE_POS_OWNER_SEED: &[u8] = b"investor_fee_pos_owner";
 pub const POLICY_SEED: &[u8] = b"policy";
 pub const PROGRESS_SEED: &[u8] = b"progress";
-pub const TREASURY_SEED: &[u8] = b"treasury";
+pub const SECONDARY_PROGRESS_SEED: &[u8] = b"progress_secondary";
+pub const SUMMARY_SEED: &[u8] = b"summary";
+pub const STATS_SEED: &[u8] = b"stats";
+pub const PAYOUT_RECORD_SEED: &[u8] = b"payout_record";
+pub const PAYOUT_RECEIPT_SEED: &[u8] = b"payout_receipt";
+pub const REGISTRY_SEED: &[u8] = b"registry";
+pub const SNAPSHOT_SEED: &[u8] = b"snapshot";
+pub const VAULT_INDEX_SEED: &[u8] = b"vault_index";
+pub const CLAIM_DISTRIBUTION_SEED: &[u8] = b"claim_distribution";
+
+/// Reserved bytes at the end of the policy and progress accounts
+pub const STATE_RESERVED_BYTES: usize = 64;
+
+/// Maximum number of investor indices a single day can process
+pub const MAX_INVESTORS_PER_DAY: u64 = 8192;
+
+/// Size of the per-day processed bitmap in `ProgressState`
+pub const PROCESSED_BITMAP_BYTES: usize = (MAX_INVESTORS_PER_DAY / 8) as usize;
+
+/// Maximum number of investors in a vault's registry
+pub const MAX_REGISTERED_INVESTORS: usize = 128;
+
+/// Neutral investor weight multiplier (1x)
+pub const BASE_MULTIPLIER_BPS: u16 = 10_000;
+
+/// Largest investor weight multiplier (5x)
+pub const MAX_MULTIPLIER_BPS: u16 = 50_000;
+
+/// Maximum number of leaves in a pull-claim distribution
+pub const MAX_CLAIM_LEAVES: u64 = 65_536;
+
+/// Maximum number of points in the investor fee-share schedule
+pub const MAX_FEE_SHARE_POINTS: usize = 8;
+
+/// Maximum number of entries in each of the global config's program allow-lists
+pub const MAX_ALLOWED_PROGRAMS: usize = 4;
+
+/// Number of vaults listed on one vault index page
+pub const VAULT_INDEX_PAGE_CAPACITY: usize = 256;
+
+/// Largest quote mint decimals a vault accepts
+pub const MAX_QUOTE_DECIMALS: u8 = 18;
+
+/// Distribution period of vaults that do not set one (24 hours)
+pub const DEFAULT_DISTRIBUTION_PERIOD: i64 = 86_400;
+
+/// Shortest distribution period the global config may allow (1 hour)
+pub const MIN_DISTRIBUTION_PERIOD: i64 = 3_600;
+
+/// Longest distribution period the global config may allow (30 days)
+pub const MAX_DISTRIBUTION_PERIOD: i64 = 30 * 86_400;
+
+/// Consecutive creator-only days allowed before the creator must acknowledge
+pub const CREATOR_ONLY_ACK_THRESHOLD_DAYS: u16 = 3;
+
+/// Minimum payouts a nonzero daily cap must fit under `Fixed` minimum payouts
+pub const MIN_PAYOUTS_PER_DAILY_CAP: u64 = 10;
+
+/// Compute units a `distribute_fees` page may spend on its investors; the rest of the
+/// 1.4M transaction limit is left to the day-start claim and the day close
+pub const PAGE_INVESTOR_COMPUTE_BUDGET: u64 = 1_000_000;
+
+/// Conservative compute cost of one investor: stream parsing, ATA checks and the transfer CPI
+pub const INVESTOR_COMPUTE_UNITS: u64 = 15_000;
+
+/// Extra compute cost of an investor whose payout receipt is created
+pub const PAYOUT_RECEIPT_COMPUTE_UNITS: u64 = 8_000;
+
+/// Extra compute cost of an investor whose payout record is read and written
+pub const PAYOUT_RECORD_COMPUTE_UNITS: u64 = 4_000;
+
+/// Extra compute cost of an investor whose Merkle proof is verified
+pub const MERKLE_PROOF_COMPUTE_UNITS: u64 = 3_000;
+
+/// Extra compute cost of an investor whose missing ATA may be created
+pub const ATA_CREATION_COMPUTE_UNITS: u64 = 25_000;
+
+/// Compute cost of an investor a page reads but does not pay: stream parsing, ATA checks and a skip log
+pub const INVESTOR_PARSE_COMPUTE_UNITS: u64 = 5_000;
 
 /// Helper functions for PDA derivation
+pub fn get_global_config_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED], program_id)
+}
+
+pub fn get_vault_index_page_pda(page: u32, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_INDEX_SEED, &page.to_le_bytes()], program_id)
+}
+
 pub fn get_investor_fee_position_owner_pda(
     vault: &Pubkey,
     program_id: &Pubkey,
@@ -110,13 +1426,105 @@ pub fn get_progress_pda(
     )
 }
 
-pub fn get_treasury_pda(
+pub fn get_secondary_progress_pda(
+    vault: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[VAULT_SEED, vault.as_ref(), SECONDARY_PROGRESS_SEED],
+        program_id,
+    )
+}
+
+pub fn get_summary_pda(
+    vault: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[VAULT_SEED, vault.as_ref(), SUMMARY_SEED],
+        program_id,
+    )
+}
+
+pub fn get_stats_pda(
+    vault: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[VAULT_SEED, vault.as_ref(), STATS_SEED],
+        program_id,
+    )
+}
+
+pub fn get_payout_record_pda(
+    vault: &Pubkey,
+    investor_quote_ata: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[VAULT_SEED, vault.as_ref(), PAYOUT_RECORD_SEED, investor_quote_ata.as_ref()],
+        program_id,
+    )
+}
+
+pub fn get_payout_receipt_pda(
     vault: &Pubkey,
     quote_mint: &Pubkey,
+    day_id: u64,
+    stream: &Pubkey,
     program_id: &Pubkey,
 ) -> (Pubkey, u8) {
     Pubkey::find_program_address(
-        &[VAULT_SEED, vault.as_ref(), TREASURY_SEED, quote_mint.as_ref()],
+        &[
+            VAULT_SEED,
+            vault.as_ref(),
+            PAYOUT_RECEIPT_SEED,
+            quote_mint.as_ref(),
+            &day_id.to_le_bytes(),
+            stream.as_ref(),
+        ],
         program_id,
     )
+}
+
+pub fn get_registry_pda(
+    vault: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[VAULT_SEED, vault.as_ref(), REGISTRY_SEED],
+        program_id,
+    )
+}
+
+pub fn get_snapshot_pda(
+    vault: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[VAULT_SEED, vault.as_ref(), SNAPSHOT_SEED],
+        program_id,
+    )
+}
+
+pub fn get_claim_distribution_pda(
+    vault: &Pubkey,
+    day_ts: i64,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[VAULT_SEED, vault.as_ref(), CLAIM_DISTRIBUTION_SEED, &day_ts.to_le_bytes()],
+        program_id,
+    )
+}
+
+/// Treasury ATA of the position owner PDA for `quote_mint`, with its canonical bump
+pub fn get_treasury_pda(
+    position_owner: &Pubkey,
+    quote_mint: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[position_owner.as_ref(), anchor_spl::token::ID.as_ref(), quote_mint.as_ref()],
+        &anchor_spl::associated_token::ID,
+    )
 }
\ No newline at end of file
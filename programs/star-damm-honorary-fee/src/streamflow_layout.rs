@@ -0,0 +1,206 @@
+use anchor_lang::prelude::*;
+use crate::error::HonoraryFeeError;
+
+/// Streamflow protocol program ID
+pub const STREAMFLOW_PROGRAM_ID: Pubkey = pubkey!("strmRqUCoQUgGUan5YhzUZa6KqdzwX5L6FpUxfmKg5m");
+
+/// Magic value at the start of every Streamflow `Contract` account ("STRMFLOW")
+pub const CONTRACT_MAGIC: u64 = 0x5354_524d_464c_4f57;
+
+/// Size of a Streamflow `Contract` (stream metadata) account
+pub const CONTRACT_LEN: usize = 1104;
+
+/// Contract versions whose layout matches the offsets below.
+/// A stream with any other version is rejected rather than misparsed.
+pub const SUPPORTED_CONTRACT_VERSIONS: &[u8] = &[1, 2];
+
+/// Byte offsets of the `Contract` fields (borsh, no padding)
+pub mod offsets {
+    pub const MAGIC: usize = 0;
+    pub const VERSION: usize = 8;
+    pub const CREATED_AT: usize = 9;
+    pub const AMOUNT_WITHDRAWN: usize = 17;
+    pub const CANCELED_AT: usize = 25;
+    pub const END_TIME: usize = 33;
+    pub const LAST_WITHDRAWN_AT: usize = 41;
+    pub const SENDER: usize = 49;
+    pub const SENDER_TOKENS: usize = 81;
+    pub const RECIPIENT: usize = 113;
+    pub const RECIPIENT_TOKENS: usize = 145;
+    pub const MINT: usize = 177;
+    pub const ESCROW_TOKENS: usize = 209;
+    pub const STREAMFLOW_TREASURY: usize = 241;
+    pub const STREAMFLOW_TREASURY_TOKENS: usize = 273;
+    pub const STREAMFLOW_FEE_TOTAL: usize = 305;
+    pub const STREAMFLOW_FEE_WITHDRAWN: usize = 313;
+    pub const STREAMFLOW_FEE_PERCENT: usize = 321;
+    pub const PARTNER: usize = 325;
+    pub const PARTNER_TOKENS: usize = 357;
+    pub const PARTNER_FEE_TOTAL: usize = 389;
+    pub const PARTNER_FEE_WITHDRAWN: usize = 397;
+    pub const PARTNER_FEE_PERCENT: usize = 405;
+
+    // CreateParams (`ix`) embedded in the contract
+    pub const START_TIME: usize = 409;
+    pub const NET_AMOUNT_DEPOSITED: usize = 417;
+    pub const PERIOD: usize = 425;
+    pub const AMOUNT_PER_PERIOD: usize = 433;
+    pub const CLIFF: usize = 441;
+    pub const CLIFF_AMOUNT: usize = 449;
+    pub const CANCELABLE_BY_SENDER: usize = 457;
+    pub const CANCELABLE_BY_RECIPIENT: usize = 458;
+    pub const AUTOMATIC_WITHDRAWAL: usize = 459;
+    pub const TRANSFERABLE_BY_SENDER: usize = 460;
+    pub const TRANSFERABLE_BY_RECIPIENT: usize = 461;
+    pub const CAN_TOPUP: usize = 462;
+    pub const STREAM_NAME: usize = 463;
+    pub const WITHDRAW_FREQUENCY: usize = 527;
+
+    /// End of the last field read by this module
+    pub const PARSED_END: usize = 535;
+}
+
+/// Fields of a Streamflow `Contract` account used by the fee distribution
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StreamContract {
+    pub magic: u64,
+    pub version: u8,
+    pub created_at: u64,
+    pub amount_withdrawn: u64,
+    pub canceled_at: u64,
+    pub end_time: u64,
+    pub last_withdrawn_at: u64,
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub recipient_tokens: Pubkey,
+    pub mint: Pubkey,
+    pub escrow_tokens: Pubkey,
+    pub partner: Pubkey,
+    pub start_time: u64,
+    pub net_amount_deposited: u64,
+    pub period: u64,
+    pub amount_per_period: u64,
+    pub cliff: u64,
+    pub cliff_amount: u64,
+    pub withdraw_frequency: u64,
+}
+
+impl StreamContract {
+    /// Parse a Streamflow `Contract` from raw account data
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= CONTRACT_LEN,
+            HonoraryFeeError::InvalidStreamAccount
+        );
+        require!(
+            read_u64(data, offsets::MAGIC)? == CONTRACT_MAGIC,
+            HonoraryFeeError::InvalidStreamAccount
+        );
+
+        let version = data[offsets::VERSION];
+        require!(
+            SUPPORTED_CONTRACT_VERSIONS.contains(&version),
+            HonoraryFeeError::UnsupportedStreamVersion
+        );
+
+        let contract = Self {
+            magic: read_u64(data, offsets::MAGIC)?,
+            version,
+            created_at: read_u64(data, offsets::CREATED_AT)?,
+            amount_withdrawn: read_u64(data, offsets::AMOUNT_WITHDRAWN)?,
+            canceled_at: read_u64(data, offsets::CANCELED_AT)?,
+            end_time: read_u64(data, offsets::END_TIME)?,
+            last_withdrawn_at: read_u64(data, offsets::LAST_WITHDRAWN_AT)?,
+            sender: read_pubkey(data, offsets::SENDER)?,
+            recipient: read_pubkey(data, offsets::RECIPIENT)?,
+            recipient_tokens: read_pubkey(data, offsets::RECIPIENT_TOKENS)?,
+            mint: read_pubkey(data, offsets::MINT)?,
+            escrow_tokens: read_pubkey(data, offsets::ESCROW_TOKENS)?,
+            partner: read_pubkey(data, offsets::PARTNER)?,
+            start_time: read_u64(data, offsets::START_TIME)?,
+            net_amount_deposited: read_u64(data, offsets::NET_AMOUNT_DEPOSITED)?,
+            period: read_u64(data, offsets::PERIOD)?,
+            amount_per_period: read_u64(data, offsets::AMOUNT_PER_PERIOD)?,
+            cliff: read_u64(data, offsets::CLIFF)?,
+            cliff_amount: read_u64(data, offsets::CLIFF_AMOUNT)?,
+            withdraw_frequency: read_u64(data, offsets::WITHDRAW_FREQUENCY)?,
+        };
+        contract.check_sanity()?;
+        Ok(contract)
+    }
+
+    /// Reject a contract whose amounts or timestamps no real stream could hold
+    ///
+    /// Nothing more than the deposit can be withdrawn or unlocked at the cliff, and the
+    /// schedule cannot end before it starts or be canceled before it was created.
+    pub fn check_sanity(&self) -> Result<()> {
+        require!(
+            self.amount_withdrawn <= self.net_amount_deposited
+                && self.cliff_amount <= self.net_amount_deposited,
+            HonoraryFeeError::InconsistentStreamAccount
+        );
+        if self.end_time > 0 {
+            require!(
+                self.start_time <= self.end_time && self.cliff <= self.end_time,
+                HonoraryFeeError::InconsistentStreamAccount
+            );
+        }
+        require!(
+            !self.is_canceled() || self.canceled_at >= self.created_at,
+            HonoraryFeeError::InconsistentStreamAccount
+        );
+        Ok(())
+    }
+
+    /// Whether the stream has been canceled
+    pub fn is_canceled(&self) -> bool {
+        self.canceled_at != 0
+    }
+
+    /// Amount released by the vesting schedule at `ts`
+    ///
+    /// Nothing vests before the cliff (or `start_time` when no cliff is set); at the
+    /// cliff `cliff_amount` unlocks, then `amount_per_period` for every full `period`
+    /// elapsed, capped at the deposited amount.
+    pub fn vested_amount_at(&self, ts: u64) -> u64 {
+        let total = self.net_amount_deposited;
+        let cliff = self.cliff.max(self.start_time);
+        if ts < cliff {
+            return 0;
+        }
+        if self.end_time > 0 && ts >= self.end_time {
+            return total;
+        }
+        
+        let periods = (ts - cliff) / self.period.max(1);
+        let vested = (self.cliff_amount as u128)
+            .saturating_add((periods as u128).saturating_mul(self.amount_per_period as u128));
+        vested.min(total as u128) as u64
+    }
+
+    /// Amount still locked in the stream at `ts`
+    ///
+    /// Derived from the vesting schedule rather than the escrow balance, so it does not
+    /// lag behind withdrawals. A canceled stream has returned its escrow and locks nothing.
+    pub fn locked_amount_at(&self, ts: u64) -> u64 {
+        if self.is_canceled() {
+            return 0;
+        }
+        let released = self.amount_withdrawn.max(self.vested_amount_at(ts));
+        self.net_amount_deposited.saturating_sub(released)
+    }
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Result<u64> {
+    let bytes = data
+        .get(offset..offset + 8)
+        .ok_or(HonoraryFeeError::InvalidStreamAccount)?;
+    Ok(u64::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> Result<Pubkey> {
+    let bytes = data
+        .get(offset..offset + 32)
+        .ok_or(HonoraryFeeError::InvalidStreamAccount)?;
+    Ok(Pubkey::try_from(bytes).unwrap())
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak::hashv;
 
 /// Mathematical utilities with overflow protection
 pub struct MathUtil;
@@ -27,6 +28,24 @@ impl MathUtil {
         a.checked_sub(b).ok_or(error!(crate::error::HonoraryFeeError::ArithmeticOverflow))
     }
     
+    /// floor(a * b / c) with a u128 intermediate, so only a result above u64::MAX overflows
+    pub fn mul_div(a: u64, b: u64, c: u64) -> Result<u64> {
+        if c == 0 {
+            return Err(error!(crate::error::HonoraryFeeError::ArithmeticOverflow));
+        }
+        Self::to_u64(a as u128 * b as u128 / c as u128)
+    }
+    
+    /// floor(amount * bps / 10000)
+    pub fn apply_bps(amount: u64, bps: u64) -> Result<u64> {
+        Self::mul_div(amount, bps, 10000)
+    }
+    
+    /// Checked downcast of a u128 intermediate
+    pub fn to_u64(value: u128) -> Result<u64> {
+        u64::try_from(value).map_err(|_| error!(crate::error::HonoraryFeeError::ArithmeticOverflow))
+    }
+    
     /// Calculate proportional distribution using floor division
     /// Returns (payout_amount, remainder)
     pub fn calculate_proportional_payout(
@@ -38,12 +57,25 @@ impl MathUtil {
             return Ok((0, total_amount));
         }
         
-        let payout = Self::safe_div(Self::safe_mul(total_amount, weight)?, total_weight)?;
+        let payout = Self::mul_div(total_amount, weight, total_weight)?;
         let remainder = Self::safe_sub(total_amount, payout)?;
         
         Ok((payout, remainder))
     }
     
+    /// Fractional part of a proportional payout, as the numerator over `total_weight`
+    pub fn calculate_proportional_remainder(
+        total_amount: u64,
+        weight: u64,
+        total_weight: u64,
+    ) -> Result<u64> {
+        if total_weight == 0 {
+            return Ok(0);
+        }
+        
+        Self::to_u64(total_amount as u128 * weight as u128 % total_weight as u128)
+    }
+    
     /// Calculate eligible investor share based on locked percentage
     /// Returns basis points (0-10000)
     pub fn calculate_eligible_share_bps(
@@ -55,18 +87,74 @@ impl MathUtil {
             return Ok(0);
         }
         
-        // f_locked(t) = locked_total(t) / Y0
-        let f_locked_bps = Self::safe_div(
-            Self::safe_mul(locked_total, 10000)?,
-            total_allocation
-        )? as u16;
-        
         // Take the minimum of investor_fee_share_bps and floor(f_locked(t) * 10000)
-        Ok(f_locked_bps.min(max_investor_share_bps))
+        Ok(Self::locked_fraction_bps(locked_total, total_allocation).min(max_investor_share_bps))
+    }
+    
+    /// f_locked(t) = locked_total(t) / Y0 in basis points, saturated at 10000
+    ///
+    /// Streams can hold more than the recorded allocation (top-ups, misconfiguration);
+    /// the excess never lifts the share above 100%. Returns 0 for a zero allocation.
+    pub fn locked_fraction_bps(locked_total: u64, total_allocation: u64) -> u16 {
+        if total_allocation == 0 {
+            return 0;
+        }
+        
+        let f_locked_bps = (locked_total as u128) * 10000 / (total_allocation as u128);
+        f_locked_bps.min(10000) as u16
     }
     
     /// Check if 24 hours have passed since last distribution
     pub fn is_24h_elapsed(last_ts: i64, current_ts: i64) -> bool {
         current_ts >= last_ts + 86400 // 86400 seconds = 24 hours
     }
-}
\ No newline at end of file
+    
+    /// Check if `period` seconds have passed since last distribution
+    pub fn is_period_elapsed(last_ts: i64, current_ts: i64, period: i64) -> bool {
+        current_ts >= last_ts.saturating_add(period)
+    }
+    
+    /// Check if `current_ts` falls in a later day than `last_ts`, with days
+    /// starting `anchor_offset` seconds after 00:00 UTC
+    pub fn is_anchored_day_elapsed(last_ts: i64, current_ts: i64, anchor_offset: i64) -> bool {
+        let day_of = |ts: i64| ts.saturating_sub(anchor_offset).div_euclid(86400);
+        day_of(current_ts) > day_of(last_ts)
+    }
+}
+/// Merkle helpers for committed investor sets (keccak, sorted sibling pairs)
+pub struct MerkleUtil;
+
+impl MerkleUtil {
+    /// Leaf hash of one investor entry at its position in the day's order
+    pub fn investor_leaf(index: u64, stream: &Pubkey, quote_ata: &Pubkey, locked_amount: u64) -> [u8; 32] {
+        hashv(&[
+            &[0u8],
+            &index.to_le_bytes(),
+            stream.as_ref(),
+            quote_ata.as_ref(),
+            &locked_amount.to_le_bytes(),
+        ]).0
+    }
+    
+    /// Leaf hash of one pull-claim allocation
+    pub fn claim_leaf(index: u64, quote_ata: &Pubkey, amount: u64) -> [u8; 32] {
+        hashv(&[
+            &[0u8],
+            &index.to_le_bytes(),
+            quote_ata.as_ref(),
+            &amount.to_le_bytes(),
+        ]).0
+    }
+    
+    /// Hash of two sibling nodes, independent of their order
+    pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        let (left, right) = if a <= b { (a, b) } else { (b, a) };
+        hashv(&[&[1u8], left, right]).0
+    }
+    
+    /// Check that `leaf` is included under `root`
+    pub fn verify(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+        let computed = proof.iter().fold(leaf, |node, sibling| Self::hash_pair(&node, sibling));
+        computed == *root
+    }
+}
@@ -0,0 +1,35 @@
+use bytemuck::Zeroable;
+use star_damm_honorary_fee::ProgressState;
+
+#[test]
+fn a_day_claims_once() {
+    let mut progress = ProgressState::zeroed();
+    progress.daily_claimed_total = 100; // claimed earlier by the other mint's crank
+
+    progress.record_claim(250, 0).unwrap();
+    assert_eq!(progress.daily_claimed_total, 350);
+    assert!(progress.is_claimed_this_day());
+
+    // A retried day start cannot count the fees twice
+    assert!(progress.record_claim(250, 0).is_err());
+    assert_eq!(progress.daily_claimed_total, 350);
+
+    // The day close clears the flag for the next day
+    progress.set_claimed_this_day(false);
+    progress.record_claim(10, 0).unwrap();
+    assert_eq!(progress.daily_claimed_total, 360);
+}
+
+#[test]
+fn base_fees_in_a_claim_are_recorded_and_quarantined() {
+    let mut progress = ProgressState::zeroed();
+
+    // Base leg of the claim_position_fee CPI, measured from the position's claim metrics
+    assert!(progress.record_claim(1_000, 40).unwrap());
+    assert_eq!(progress.daily_claimed_total, 1_000);
+    assert_eq!(progress.daily_claimed_base, 40);
+
+    let mut clean = ProgressState::zeroed();
+    assert!(!clean.record_claim(1_000, 0).unwrap());
+    assert_eq!(clean.daily_claimed_base, 0);
+}
@@ -0,0 +1,227 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_spl::token_2022::spl_token_2022::state::Account as SplTokenAccount;
+use star_damm_honorary_fee::{cp_amm_cpi::*, migrate_quote_mint::move_honorary_position, PolicyState};
+
+/// Expected account layout for one cp-amm instruction: (name, is_signer, is_writable)
+///
+/// Transcribed from the cp-amm (DAMM v2) IDL account lists for each instruction.
+type Layout = &'static [(&'static str, bool, bool)];
+
+const CREATE_POSITION_LAYOUT: Layout = &[
+    ("owner", false, false),
+    ("position_nft_mint", true, true),
+    ("position_nft_account", false, true),
+    ("pool", false, true),
+    ("position", false, true),
+    ("pool_authority", false, false),
+    ("payer", true, true),
+    ("token_program", false, false),
+    ("system_program", false, false),
+    ("event_authority", false, false),
+    ("program", false, false),
+];
+
+const CLAIM_POSITION_FEE_LAYOUT: Layout = &[
+    ("pool_authority", false, false),
+    ("pool", false, false),
+    ("position", false, true),
+    ("token_a_account", false, true),
+    ("token_b_account", false, true),
+    ("token_a_vault", false, true),
+    ("token_b_vault", false, true),
+    ("token_a_mint", false, false),
+    ("token_b_mint", false, false),
+    ("position_nft_account", false, false),
+    ("owner", true, false),
+    ("token_a_program", false, false),
+    ("token_b_program", false, false),
+    ("event_authority", false, false),
+    ("program", false, false),
+];
+
+fn sighash(name: &str) -> [u8; 8] {
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash(format!("global:{}", name).as_bytes()).to_bytes()[..8]);
+    discriminator
+}
+
+/// One distinct key per layout slot so any reordering is visible
+fn keys_for(layout: Layout) -> Vec<Pubkey> {
+    (0..layout.len()).map(|_| Pubkey::new_unique()).collect()
+}
+
+fn assert_layout(metas: &[AccountMeta], keys: &[Pubkey], layout: Layout) {
+    assert_eq!(metas.len(), layout.len());
+    for (i, (meta, (name, is_signer, is_writable))) in metas.iter().zip(layout).enumerate() {
+        assert_eq!(meta.pubkey, keys[i], "{} is out of order", name);
+        assert_eq!(meta.is_signer, *is_signer, "{} signer flag", name);
+        assert_eq!(meta.is_writable, *is_writable, "{} writable flag", name);
+    }
+}
+
+#[test]
+fn discriminators_match_anchor_sighash() {
+    assert_eq!(CREATE_POSITION_DISCRIMINATOR, sighash("create_position"));
+    assert_eq!(CLAIM_POSITION_FEE_DISCRIMINATOR, sighash("claim_position_fee"));
+
+    let mut account_discriminator = [0u8; 8];
+    account_discriminator.copy_from_slice(&hash(b"account:Position").to_bytes()[..8]);
+    assert_eq!(POSITION_ACCOUNT_DISCRIMINATOR, account_discriminator);
+}
+
+#[test]
+fn create_position_account_order() {
+    let k = keys_for(CREATE_POSITION_LAYOUT);
+    let keys = CreatePositionKeys {
+        owner: k[0],
+        position_nft_mint: k[1],
+        position_nft_account: k[2],
+        pool: k[3],
+        position: k[4],
+        pool_authority: k[5],
+        payer: k[6],
+        token_program: k[7],
+        system_program: k[8],
+        event_authority: k[9],
+        program: k[10],
+    };
+
+    let ix = keys.instruction();
+    assert_eq!(ix.program_id, k[10]);
+    assert_eq!(ix.data, CREATE_POSITION_DISCRIMINATOR.to_vec());
+    assert_layout(&ix.accounts, &k, CREATE_POSITION_LAYOUT);
+}
+
+#[test]
+fn claim_position_fee_account_order() {
+    let k = keys_for(CLAIM_POSITION_FEE_LAYOUT);
+    let keys = ClaimPositionFeeKeys {
+        pool_authority: k[0],
+        pool: k[1],
+        position: k[2],
+        token_a_account: k[3],
+        token_b_account: k[4],
+        token_a_vault: k[5],
+        token_b_vault: k[6],
+        token_a_mint: k[7],
+        token_b_mint: k[8],
+        position_nft_account: k[9],
+        owner: k[10],
+        token_a_program: k[11],
+        token_b_program: k[12],
+        event_authority: k[13],
+        program: k[14],
+    };
+
+    let ix = keys.instruction();
+    assert_eq!(ix.program_id, k[14]);
+    assert_eq!(ix.data, CLAIM_POSITION_FEE_DISCRIMINATOR.to_vec());
+    assert_layout(&ix.accounts, &k, CLAIM_POSITION_FEE_LAYOUT);
+}
+
+#[test]
+fn position_fees_are_oriented_by_quote_mint() {
+    let fees = PositionFees {
+        fee_a_pending: 7,
+        fee_b_pending: 3,
+        ..Default::default()
+    };
+    let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    assert_eq!(fees.quote_and_base_pending(&mint_a, &mint_b, &mint_a), Some((7, 3)));
+    assert_eq!(fees.quote_and_base_pending(&mint_a, &mint_b, &mint_b), Some((3, 7)));
+    assert_eq!(fees.quote_and_base_pending(&mint_a, &mint_b, &Pubkey::new_unique()), None);
+}
+
+#[test]
+fn claimed_fees_come_from_position_metrics() {
+    let mut data = vec![0u8; 408];
+    data[..8].copy_from_slice(&POSITION_ACCOUNT_DISCRIMINATOR);
+    data[136..144].copy_from_slice(&50u64.to_le_bytes()); // fee_a_pending
+    data[200..208].copy_from_slice(&1_000u64.to_le_bytes()); // metrics.total_claimed_a_fee
+    data[208..216].copy_from_slice(&20u64.to_le_bytes()); // metrics.total_claimed_b_fee
+    let before = PositionFees::try_from_bytes(&data).unwrap();
+
+    data[136..144].copy_from_slice(&0u64.to_le_bytes());
+    data[200..208].copy_from_slice(&1_075u64.to_le_bytes());
+    let after = PositionFees::try_from_bytes(&data).unwrap();
+    let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    assert_eq!(after.quote_and_base_claimed_since(&before, &mint_a, &mint_b, &mint_a), Some((75, 0)));
+    assert_eq!(after.quote_and_base_claimed_since(&before, &mint_a, &mint_b, &mint_b), Some((0, 75)));
+    assert_eq!(before.quote_and_base_claimed_since(&after, &mint_a, &mint_b, &mint_a), None);
+}
+
+#[test]
+fn donations_during_a_claim_are_not_counted_as_claimed() {
+    let mut data = vec![0u8; 408];
+    data[..8].copy_from_slice(&POSITION_ACCOUNT_DISCRIMINATOR);
+    let before = PositionFees::try_from_bytes(&data).unwrap();
+
+    // The claim paid 250 quote; someone else sent another 1_000 to the treasury meanwhile
+    data[208..216].copy_from_slice(&250u64.to_le_bytes()); // metrics.total_claimed_b_fee
+    let after = PositionFees::try_from_bytes(&data).unwrap();
+    let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (claimed, _) = after.quote_and_base_claimed_since(&before, &mint_a, &mint_b, &mint_b).unwrap();
+    let (treasury_before, treasury_after) = (5_000, 6_250);
+
+    assert_eq!(claimed, 250);
+    assert!(require_claim_received(treasury_before, treasury_after, claimed).is_ok());
+
+    // A claim whose tokens did not arrive in the treasury is rejected
+    assert!(require_claim_received(treasury_before, treasury_before + 249, claimed).is_err());
+}
+
+fn position_data(pool: Pubkey, nft_mint: Pubkey) -> Vec<u8> {
+    let mut data = vec![0u8; 408];
+    data[..8].copy_from_slice(&POSITION_ACCOUNT_DISCRIMINATOR);
+    data[8..40].copy_from_slice(pool.as_ref());
+    data[40..72].copy_from_slice(nft_mint.as_ref());
+    data
+}
+
+fn nft_account(mint: Pubkey, owner: Pubkey) -> SplTokenAccount {
+    SplTokenAccount { mint, owner, amount: 1, ..Default::default() }
+}
+
+/// The position check `distribute_fees` runs on every crank, against the recorded pool and position
+fn crank_position_check(policy: &PolicyState, position: &Pubkey, data: &[u8], nft: &SplTokenAccount, owner_pda: &Pubkey) -> bool {
+    *position == policy.position
+        && verify_position_authority(&PositionFees::try_from_bytes(data).unwrap(), nft, owner_pda, &policy.pool).is_ok()
+}
+
+#[test]
+fn a_migrated_vault_cranks_against_the_new_position() {
+    let owner_pda = Pubkey::new_unique();
+    let (old_pool, new_pool) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (old_position, new_position) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (old_nft, new_nft) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let old_data = position_data(old_pool, old_nft);
+    let new_data = position_data(new_pool, new_nft);
+    let (old_holder, new_holder) = (nft_account(old_nft, owner_pda), nft_account(new_nft, owner_pda));
+
+    let mut policy = PolicyState::try_deserialize_unchecked(&mut &vec![0u8; PolicyState::LEN][..]).unwrap();
+    policy.pool = old_pool;
+    policy.position = old_position;
+    assert!(crank_position_check(&policy, &old_position, &old_data, &old_holder, &owner_pda));
+
+    // Migration refuses a replacement outside the new pool or not held by the PDA, leaving the policy as it was
+    assert!(move_honorary_position(&mut policy, new_pool, old_position, &old_data, &old_holder, &owner_pda).is_err());
+    let stranger = nft_account(new_nft, Pubkey::new_unique());
+    assert!(move_honorary_position(&mut policy, new_pool, new_position, &new_data, &stranger, &owner_pda).is_err());
+    assert_eq!((policy.pool, policy.position), (old_pool, old_position));
+
+    move_honorary_position(&mut policy, new_pool, new_position, &new_data, &new_holder, &owner_pda).unwrap();
+
+    // The next crank passes with the new position and rejects the old one
+    assert!(crank_position_check(&policy, &new_position, &new_data, &new_holder, &owner_pda));
+    assert!(!crank_position_check(&policy, &old_position, &old_data, &old_holder, &owner_pda));
+}
+
+#[test]
+fn event_authority_is_derived_from_cp_amm() {
+    let (expected, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &CP_AMM_PROGRAM_ID);
+    assert_eq!(get_event_authority_pda(&CP_AMM_PROGRAM_ID).0, expected);
+}
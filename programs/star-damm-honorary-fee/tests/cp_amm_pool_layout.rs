@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use star_damm_honorary_fee::{cp_amm_cpi::PositionFees, CpAmmPool, PoolValidator};
+
+/// Q64.64 square root price of 1.0
+const SQRT_PRICE_ONE: u128 = 1 << 64;
+
+/// Pool fixture encoded with literal byte offsets of the cp-amm `Pool` layout,
+/// kept independent of the module's offset table so drift in either is caught
+struct Fixture {
+    data: Vec<u8>,
+    token_a_mint: Pubkey,
+    token_b_mint: Pubkey,
+    token_a_vault: Pubkey,
+    token_b_vault: Pubkey,
+}
+
+fn pool_fixture(sqrt_price: u128, pool_status: u8) -> Fixture {
+    let mut fixture = Fixture {
+        data: vec![0u8; 1112],
+        token_a_mint: Pubkey::new_unique(),
+        token_b_mint: Pubkey::new_unique(),
+        token_a_vault: Pubkey::new_unique(),
+        token_b_vault: Pubkey::new_unique(),
+    };
+    let data = &mut fixture.data;
+
+    data[..8].copy_from_slice(&hash(b"account:Pool").to_bytes()[..8]);
+    data[8..16].copy_from_slice(&2_500_000u64.to_le_bytes()); // pool_fees.base_fee.cliff_fee_numerator
+    data[168..200].copy_from_slice(fixture.token_a_mint.as_ref());
+    data[200..232].copy_from_slice(fixture.token_b_mint.as_ref());
+    data[232..264].copy_from_slice(fixture.token_a_vault.as_ref());
+    data[264..296].copy_from_slice(fixture.token_b_vault.as_ref());
+    data[360..376].copy_from_slice(&1_000_000u128.to_le_bytes()); // liquidity
+    data[424..440].copy_from_slice(&(SQRT_PRICE_ONE / 4).to_le_bytes()); // sqrt_min_price
+    data[440..456].copy_from_slice(&(SQRT_PRICE_ONE * 4).to_le_bytes()); // sqrt_max_price
+    data[456..472].copy_from_slice(&sqrt_price.to_le_bytes());
+    data[480] = 1; // activation_type
+    data[481] = pool_status;
+    data[484] = 1; // collect_fee_mode
+
+    fixture
+}
+
+#[test]
+fn discriminator_matches_anchor_account_hash() {
+    assert_eq!(CpAmmPool::DISCRIMINATOR[..], hash(b"account:Pool").to_bytes()[..8]);
+}
+
+#[test]
+fn parses_pool_fixture() {
+    let fixture = pool_fixture(SQRT_PRICE_ONE * 2, 0);
+    let pool = CpAmmPool::try_deserialize(&fixture.data).unwrap();
+
+    assert_eq!(pool.token_a_mint, fixture.token_a_mint);
+    assert_eq!(pool.token_b_mint, fixture.token_b_mint);
+    assert_eq!(pool.token_a_vault, fixture.token_a_vault);
+    assert_eq!(pool.token_b_vault, fixture.token_b_vault);
+    assert_eq!(pool.sqrt_min_price, SQRT_PRICE_ONE / 4);
+    assert_eq!(pool.sqrt_max_price, SQRT_PRICE_ONE * 4);
+    assert_eq!(pool.sqrt_price, SQRT_PRICE_ONE * 2);
+    assert_eq!(pool.pool_status, 0);
+}
+
+#[test]
+fn reads_disabled_status() {
+    let fixture = pool_fixture(SQRT_PRICE_ONE, 1);
+    assert_eq!(CpAmmPool::try_deserialize(&fixture.data).unwrap().pool_status, 1);
+}
+
+#[test]
+fn rejects_other_discriminators() {
+    let mut fixture = pool_fixture(SQRT_PRICE_ONE, 0);
+    fixture.data[..8].copy_from_slice(&hash(b"account:Position").to_bytes()[..8]);
+    assert!(CpAmmPool::try_deserialize(&fixture.data).is_err());
+
+    fixture.data[..8].copy_from_slice(&hash(b"account:LbPair").to_bytes()[..8]);
+    assert!(CpAmmPool::try_deserialize(&fixture.data).is_err());
+}
+
+#[test]
+fn rejects_truncated_accounts() {
+    let fixture = pool_fixture(SQRT_PRICE_ONE, 0);
+    assert!(CpAmmPool::try_deserialize(&fixture.data[..481]).is_err());
+}
+
+#[test]
+fn pool_mint_errors_name_the_mismatch() {
+    let mut fixture = pool_fixture(SQRT_PRICE_ONE, 0);
+    let (key, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let mut lamports = 0;
+    let pool_info = AccountInfo::new(&key, false, false, &mut lamports, &mut fixture.data, &owner, false, 0);
+    let error_name = |quote_mint: &Pubkey, base_mint: &Pubkey| {
+        match PoolValidator::validate_quote_only_config(&pool_info, &owner, quote_mint, base_mint) {
+            Ok(()) => None,
+            Err(Error::AnchorError(error)) => Some(error.error_name),
+            Err(error) => panic!("unexpected error {error}"),
+        }
+    };
+
+    assert_eq!(error_name(&fixture.token_b_mint, &fixture.token_a_mint), None);
+    assert_eq!(error_name(&Pubkey::new_unique(), &fixture.token_a_mint).as_deref(), Some("PoolMintMismatch"));
+    assert_eq!(error_name(&fixture.token_b_mint, &fixture.token_b_mint).as_deref(), Some("QuoteMintIsBaseMint"));
+}
+
+#[test]
+fn owed_base_fees_follow_the_quote_side() {
+    let fixture = pool_fixture(SQRT_PRICE_ONE * 2, 0);
+    let pool = CpAmmPool::try_deserialize(&fixture.data).unwrap();
+
+    // Base fees are read from the position's fee-owed fields, whatever the pool's price
+    let mut position = vec![0u8; 408];
+    position[..8].copy_from_slice(&hash(b"account:Position").to_bytes()[..8]);
+    position[144..152].copy_from_slice(&25u64.to_le_bytes()); // fee_b_pending
+    let fees = PositionFees::try_from_bytes(&position).unwrap();
+
+    let owed = |quote_mint: &Pubkey| fees.quote_and_base_pending(&pool.token_a_mint, &pool.token_b_mint, quote_mint);
+    assert_eq!(owed(&fixture.token_a_mint), Some((0, 25)));
+    assert_eq!(owed(&fixture.token_b_mint), Some((25, 0)));
+}
+
+#[test]
+fn claim_routes_the_quote_leg_to_the_treasury() {
+    let fixture = pool_fixture(SQRT_PRICE_ONE, 0);
+    let pool = CpAmmPool::try_deserialize(&fixture.data).unwrap();
+    let unknown_mint = Pubkey::new_unique();
+
+    // (token_a_account, token_b_account)
+    assert_eq!(pool.orient_accounts(&fixture.token_a_mint, "treasury", "base"), Some(("treasury", "base")));
+    assert_eq!(pool.orient_accounts(&fixture.token_b_mint, "treasury", "base"), Some(("base", "treasury")));
+    assert_eq!(pool.orient_accounts(&unknown_mint, "treasury", "base"), None);
+}
+
+#[test]
+fn claimed_amounts_follow_the_claimed_legs() {
+    let fixture = pool_fixture(SQRT_PRICE_ONE, 0);
+    let pool = CpAmmPool::try_deserialize(&fixture.data).unwrap();
+
+    // Position before and after a claim_position_fee that paid 40 of token A and 900 of token B
+    let mut position = vec![0u8; 408];
+    position[..8].copy_from_slice(&hash(b"account:Position").to_bytes()[..8]);
+    position[136..144].copy_from_slice(&40u64.to_le_bytes()); // fee_a_pending
+    position[144..152].copy_from_slice(&900u64.to_le_bytes()); // fee_b_pending
+    let before = PositionFees::try_from_bytes(&position).unwrap();
+    position[136..152].fill(0);
+    position[200..208].copy_from_slice(&40u64.to_le_bytes()); // metrics.total_claimed_a_fee
+    position[208..216].copy_from_slice(&900u64.to_le_bytes()); // metrics.total_claimed_b_fee
+    let after = PositionFees::try_from_bytes(&position).unwrap();
+
+    let claimed = |quote_mint: &Pubkey| {
+        after.quote_and_base_claimed_since(&before, &pool.token_a_mint, &pool.token_b_mint, quote_mint)
+    };
+    assert_eq!(claimed(&fixture.token_b_mint), Some((900, 40)));
+    assert_eq!(claimed(&fixture.token_a_mint), Some((40, 900)));
+}
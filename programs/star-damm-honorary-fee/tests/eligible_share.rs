@@ -0,0 +1,69 @@
+use bytemuck::Zeroable;
+use star_damm_honorary_fee::{finalize_claim_root::claim_allocation_cap, DistributionOrder, MathUtil, ProgressState};
+
+#[test]
+fn zero_allocation_has_no_locked_fraction() {
+    assert_eq!(MathUtil::locked_fraction_bps(1_000, 0), 0);
+    assert_eq!(MathUtil::calculate_eligible_share_bps(1_000, 0, 10000).unwrap(), 0);
+}
+
+#[test]
+fn locked_fraction_is_floored() {
+    assert_eq!(MathUtil::locked_fraction_bps(0, 1_000), 0);
+    assert_eq!(MathUtil::locked_fraction_bps(500, 1_000), 5000);
+    assert_eq!(MathUtil::locked_fraction_bps(1, 3), 3333);
+    assert_eq!(MathUtil::locked_fraction_bps(999_999, 1_000_000), 9999);
+}
+
+#[test]
+fn fully_locked_is_exactly_10000() {
+    assert_eq!(MathUtil::locked_fraction_bps(1_000, 1_000), 10000);
+    assert_eq!(MathUtil::locked_fraction_bps(u64::MAX, u64::MAX), 10000);
+}
+
+#[test]
+fn over_allocation_saturates_at_10000() {
+    assert_eq!(MathUtil::locked_fraction_bps(1_001, 1_000), 10000);
+    assert_eq!(MathUtil::locked_fraction_bps(2_000, 1_000), 10000);
+    // 6.5536x the allocation used to wrap through u16 to 0 bps
+    assert_eq!(MathUtil::locked_fraction_bps(65_536, 10_000), 10000);
+    assert_eq!(MathUtil::locked_fraction_bps(70_000, 10_000), 10000);
+}
+
+#[test]
+fn large_locked_totals_do_not_overflow() {
+    assert_eq!(MathUtil::locked_fraction_bps(u64::MAX, 1), 10000);
+    assert_eq!(MathUtil::calculate_eligible_share_bps(u64::MAX, 1, 10000).unwrap(), 10000);
+    assert_eq!(MathUtil::locked_fraction_bps(u64::MAX / 2, u64::MAX), 4999);
+}
+
+#[test]
+fn eligible_share_is_capped_by_the_investor_share() {
+    assert_eq!(MathUtil::calculate_eligible_share_bps(500, 1_000, 7000).unwrap(), 5000);
+    assert_eq!(MathUtil::calculate_eligible_share_bps(900, 1_000, 7000).unwrap(), 7000);
+    assert_eq!(MathUtil::calculate_eligible_share_bps(5_000, 1_000, 7000).unwrap(), 7000);
+    assert_eq!(MathUtil::calculate_eligible_share_bps(5_000, 1_000, 10000).unwrap(), 10000);
+}
+
+#[test]
+fn pull_claim_cap_follows_a_partial_unlock() {
+    // 10% of the 1M allocation is still locked; investors are entitled to up to 70% of the day
+    let (total_locked, total_investor_allocation) = (100_000, 1_000_000);
+    let mut progress = ProgressState::zeroed();
+    progress.daily_claimed_total = 10_000;
+    progress.investor_fee_share_bps = 7000;
+
+    let order = DistributionOrder::InvestorsFirst;
+    progress.eligible_share_bps = order.eligible_share_bps(total_locked, total_investor_allocation, 7000).unwrap();
+    assert_eq!(progress.eligible_share_bps, 1000);
+    assert_eq!(claim_allocation_cap(&progress, order).unwrap(), (1_000, 0));
+
+    // Creator first: the 30% cut comes off the top and investors get 10% of the rest
+    let order = DistributionOrder::CreatorFirst;
+    progress.eligible_share_bps = order.eligible_share_bps(total_locked, total_investor_allocation, 7000).unwrap();
+    assert_eq!(claim_allocation_cap(&progress, order).unwrap(), (700, 0));
+
+    // The daily cap clips the locked-weighted share, not the full investor share
+    progress.daily_cap = 500;
+    assert_eq!(claim_allocation_cap(&progress, order).unwrap(), (500, 200));
+}
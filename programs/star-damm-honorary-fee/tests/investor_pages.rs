@@ -0,0 +1,55 @@
+use anchor_lang::AccountDeserialize;
+use star_damm_honorary_fee::{
+    distribute_fees::investor_day_payout, PolicyState, INVESTOR_COMPUTE_UNITS, INVESTOR_PARSE_COMPUTE_UNITS,
+    PAGE_INVESTOR_COMPUTE_BUDGET,
+};
+
+/// Pay one page against the day's fixed allocation and locked total, as `plan_investor_page` does
+fn pay_page(day_allocation: u64, total_locked_today: u64, distributed: &mut u64, weights: &[u64]) -> Vec<u64> {
+    let mut remaining = day_allocation - *distributed;
+    let payouts: Vec<u64> = weights
+        .iter()
+        .map(|&weight| investor_day_payout(day_allocation, weight, total_locked_today, &mut remaining).unwrap())
+        .collect();
+    *distributed += payouts.iter().sum::<u64>();
+    payouts
+}
+
+#[test]
+fn pages_of_equal_weight_are_paid_equally() {
+    // Two pages of two investors, 250 locked each, share a 10_000 allocation
+    let (day_allocation, total_locked_today) = (10_000, 1_000);
+    let mut distributed = 0;
+
+    let first = pay_page(day_allocation, total_locked_today, &mut distributed, &[250, 250]);
+    let second = pay_page(day_allocation, total_locked_today, &mut distributed, &[250, 250]);
+
+    assert_eq!(first, vec![2_500, 2_500]);
+    assert_eq!(second, first);
+    assert_eq!(distributed, day_allocation);
+}
+
+#[test]
+fn later_pages_cannot_exceed_the_day_allocation() {
+    // The second page's streams were topped up after the first page fixed the locked total
+    let (day_allocation, total_locked_today) = (10_000, 1_000);
+    let mut distributed = 0;
+
+    assert_eq!(pay_page(day_allocation, total_locked_today, &mut distributed, &[500]), vec![5_000]);
+    assert_eq!(pay_page(day_allocation, total_locked_today, &mut distributed, &[400, 400]), vec![4_000, 1_000]);
+    assert_eq!(distributed, day_allocation);
+}
+
+#[test]
+fn investors_read_outside_the_page_count_against_the_budget() {
+    let policy = PolicyState::try_deserialize_unchecked(&mut &vec![0u8; PolicyState::LEN][..]).unwrap();
+    let page_size = policy.max_investors_per_page(false);
+    assert!(policy.page_compute_units(page_size, page_size as u64, false) <= PAGE_INVESTOR_COMPUTE_BUDGET);
+
+    // A stream page of 10 passing 100 investors parses 90 it does not pay
+    assert_eq!(
+        policy.page_compute_units(10, 100, false),
+        10 * INVESTOR_COMPUTE_UNITS + 90 * INVESTOR_PARSE_COMPUTE_UNITS
+    );
+    assert!(policy.page_compute_units(10, 500, false) > PAGE_INVESTOR_COMPUTE_BUDGET);
+}
@@ -0,0 +1,46 @@
+use star_damm_honorary_fee::MathUtil;
+
+const MAX: u64 = u64::MAX;
+
+#[test]
+fn mul_div_keeps_products_above_u64() {
+    // claimed * bps overflowed u64 before the division
+    assert_eq!(MathUtil::mul_div(MAX, 10000, 10000).unwrap(), MAX);
+    assert_eq!(MathUtil::mul_div(MAX, 5000, 10000).unwrap(), MAX / 2);
+    assert_eq!(MathUtil::mul_div(MAX, MAX, MAX).unwrap(), MAX);
+    assert_eq!(MathUtil::mul_div(MAX - 1, MAX, MAX).unwrap(), MAX - 1);
+}
+
+#[test]
+fn mul_div_rejects_results_above_u64() {
+    assert!(MathUtil::mul_div(MAX, 2, 1).is_err());
+    assert!(MathUtil::mul_div(MAX, MAX, MAX - 1).is_err());
+    assert!(MathUtil::mul_div(1, 1, 0).is_err());
+}
+
+#[test]
+fn apply_bps_at_the_u64_boundary() {
+    assert_eq!(MathUtil::apply_bps(MAX, 0).unwrap(), 0);
+    assert_eq!(MathUtil::apply_bps(MAX, 1).unwrap(), MAX / 10000);
+    assert_eq!(MathUtil::apply_bps(MAX, 10000).unwrap(), MAX);
+    assert_eq!(MathUtil::apply_bps(9999, 1).unwrap(), 0);
+}
+
+#[test]
+fn proportional_payout_with_large_weights() {
+    // Whole amount to a single investor holding every locked token
+    assert_eq!(MathUtil::calculate_proportional_payout(MAX, MAX, MAX).unwrap(), (MAX, 0));
+    
+    // Two equal investors split an odd amount; the unit of residue stays behind
+    assert_eq!(MathUtil::calculate_proportional_payout(MAX, MAX / 2, MAX - 1).unwrap(), (MAX / 2, MAX / 2 + 1));
+    assert_eq!(MathUtil::calculate_proportional_remainder(MAX, MAX / 2, MAX - 1).unwrap(), MAX / 2);
+    
+    assert_eq!(MathUtil::calculate_proportional_payout(1_000, 1, 0).unwrap(), (0, 1_000));
+    assert_eq!(MathUtil::calculate_proportional_remainder(1_000, 1, 0).unwrap(), 0);
+}
+
+#[test]
+fn to_u64_is_a_checked_downcast() {
+    assert_eq!(MathUtil::to_u64(MAX as u128).unwrap(), MAX);
+    assert!(MathUtil::to_u64(MAX as u128 + 1).is_err());
+}
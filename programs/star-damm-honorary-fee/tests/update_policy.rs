@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use star_damm_honorary_fee::{
+    MinPayoutMode, ParameterBounds, PolicyChange, PolicyState, UpdatePolicyParams, DEFAULT_DISTRIBUTION_PERIOD,
+};
+
+const HOUR: i64 = 3_600;
+
+fn bounds() -> ParameterBounds {
+    ParameterBounds {
+        max_investor_fee_share_bps: 8_000,
+        max_page_size: 50,
+        max_investors: 1_000,
+        max_creator_vesting_period: 0,
+        min_distribution_period: HOUR,
+        max_distribution_period: 30 * 24 * HOUR,
+    }
+}
+
+fn policy() -> PolicyState {
+    let mut policy = PolicyState::try_deserialize_unchecked(&mut &vec![0u8; PolicyState::LEN][..]).unwrap();
+    policy.investor_fee_share_bps = 5_000;
+    policy.daily_cap = 1_000_000;
+    policy.min_payout_mode = MinPayoutMode::Fixed;
+    policy.min_payout_lamports = 1_000;
+    policy.distribution_period = DEFAULT_DISTRIBUTION_PERIOD;
+    policy
+}
+
+fn error_name(result: Result<Vec<PolicyChange>>) -> String {
+    match result {
+        Err(Error::AnchorError(error)) => error.error_name,
+        other => panic!("expected a program error, got {other:?}"),
+    }
+}
+
+#[test]
+fn only_changed_parameters_are_reported() {
+    let mut policy = policy();
+    let params = UpdatePolicyParams {
+        investor_fee_share_bps: Some(6_000),
+        daily_cap: Some(1_000_000),
+        distribution_period: Some(12 * HOUR),
+        ..Default::default()
+    };
+
+    let changes = params.apply(&mut policy, &bounds()).unwrap();
+    assert_eq!(
+        changes,
+        vec![
+            PolicyChange::InvestorFeeShareBps { previous: 5_000, new: 6_000 },
+            PolicyChange::DistributionPeriod { previous: DEFAULT_DISTRIBUTION_PERIOD, new: 12 * HOUR },
+        ]
+    );
+    assert_eq!(policy.investor_fee_share_bps, 6_000);
+    assert_eq!(policy.distribution_period, 12 * HOUR);
+
+    assert!(UpdatePolicyParams::default().apply(&mut policy, &bounds()).unwrap().is_empty());
+}
+
+#[test]
+fn cap_must_fit_several_fixed_minimum_payouts() {
+    // The cap and minimum are checked together, whichever of them changes
+    let lower_cap = UpdatePolicyParams { daily_cap: Some(5_000), ..Default::default() };
+    assert_eq!(error_name(lower_cap.apply(&mut policy(), &bounds())), "MinPayoutExceedsCap");
+
+    let higher_minimum = UpdatePolicyParams { min_payout_lamports: Some(200_000), ..Default::default() };
+    assert_eq!(error_name(higher_minimum.apply(&mut policy(), &bounds())), "MinPayoutExceedsCap");
+
+    let allocation_bps = UpdatePolicyParams {
+        daily_cap: Some(5_000),
+        min_payout_mode: Some(MinPayoutMode::AllocationBps),
+        ..Default::default()
+    };
+    assert!(allocation_bps.apply(&mut policy(), &bounds()).is_ok());
+}
+
+#[test]
+fn distribution_period_stays_within_the_global_bounds() {
+    let period = |seconds: i64| UpdatePolicyParams { distribution_period: Some(seconds), ..Default::default() };
+
+    assert_eq!(error_name(period(HOUR - 1).apply(&mut policy(), &bounds())), "ParameterOutOfBounds");
+    assert_eq!(error_name(period(30 * 24 * HOUR + 1).apply(&mut policy(), &bounds())), "ParameterOutOfBounds");
+    assert!(period(HOUR).apply(&mut policy(), &bounds()).is_ok());
+
+    // Anchored days are always 24 hours long
+    let mut anchored = policy();
+    anchored.day_anchor_offset = Some(0);
+    assert_eq!(error_name(period(HOUR).apply(&mut anchored, &bounds())), "InvalidDistributionPeriod");
+}
+
+#[test]
+fn a_rejected_update_leaves_the_policy_unchanged() {
+    let mut policy = policy();
+    let params = UpdatePolicyParams {
+        investor_fee_share_bps: Some(9_000),
+        daily_cap: Some(2_000_000),
+        ..Default::default()
+    };
+
+    assert_eq!(error_name(params.apply(&mut policy, &bounds())), "ParameterOutOfBounds");
+    assert_eq!(policy.investor_fee_share_bps, 5_000);
+    assert_eq!(policy.daily_cap, 1_000_000);
+}
@@ -28,6 +28,9 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
   let positionOwnerPda: PublicKey;
   let treasuryPda: PublicKey;
 
+  // Mock cp-amm pool (in real test, this would be a proper cp-amm pool)
+  const mockPool = Keypair.generate();
+
   // Test configuration
   const INVESTOR_FEE_SHARE_BPS = 7500; // 75%
   const DAILY_CAP = 1000000; // 1M tokens
@@ -119,20 +122,40 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
 
   describe("Honorary Position Initialization", () => {
     it("Should initialize honorary position with correct configuration", async () => {
-      // Mock pool account (in real test, this would be a proper cp-amm pool)
-      const mockPool = Keypair.generate();
       const mockPosition = Keypair.generate();
 
       // Fund the mock pool account with some data
       await provider.connection.requestAirdrop(mockPool.publicKey, 1000000000);
 
       const tx = await program.methods
-        .initializeHonoraryPosition(
-          INVESTOR_FEE_SHARE_BPS,
-          new anchor.BN(DAILY_CAP),
-          new anchor.BN(MIN_PAYOUT_LAMPORTS),
-          new anchor.BN(TOTAL_INVESTOR_ALLOCATION)
-        )
+        .initializeHonoraryPosition({
+          investorFeeShareBps: INVESTOR_FEE_SHARE_BPS,
+          dailyCap: new anchor.BN(DAILY_CAP),
+          minPayoutLamports: new anchor.BN(MIN_PAYOUT_LAMPORTS),
+          totalInvestorAllocation: new anchor.BN(TOTAL_INVESTOR_ALLOCATION),
+          dustDestination: { creator: {} },
+          creatorVestingPeriod: new anchor.BN(0), // instant creator payouts
+          minPageSize: 1,
+          maxPageSize: 20,
+          merkleInvestorSet: false,
+          pullClaims: false,
+          payoutRecords: false,
+          payoutReceipts: false,
+          carryCappedExcess: false,
+          distributionOrder: { investorsFirst: {} },
+          roundingMode: { floor: {} },
+          feeShareSchedule: [],
+          dayAnchorOffset: null,
+          maxInvestors: 128,
+          deferCreatorPayouts: false,
+          minPayoutMode: { fixed: {} },
+          minPayoutBps: 0,
+          minCrankIntervalSeconds: 0,
+          creatorPaidPerPage: false,
+          streamSender: payer.publicKey,
+          residueDestination: { creator: {} },
+          distributionPeriod: new BN(86400),
+        })
         .accounts({
           payer: payer.publicKey,
           vault: vault.publicKey,
@@ -146,6 +169,7 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
           treasury: treasuryPda,
           position: mockPosition.publicKey,
           cpAmmProgram: new PublicKey("11111111111111111111111111111111"), // Mock program ID
+          streamflowProgram: new PublicKey("11111111111111111111111111111111"),
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -174,23 +198,44 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
         progressPda
       );
       expect(progressAccount.lastDistributionTs.toNumber()).to.equal(0);
-      expect(progressAccount.dayComplete).to.equal(true);
+      expect(progressAccount.dayComplete).to.equal(1);
       expect(progressAccount.dailyDistributed.toNumber()).to.equal(0);
       expect(progressAccount.carryOver.toNumber()).to.equal(0);
     });
 
     it("Should reject invalid fee share percentage", async () => {
-      const mockPool = Keypair.generate();
       const mockPosition = Keypair.generate();
 
       try {
         await program.methods
-          .initializeHonoraryPosition(
-            10001, // Invalid: > 10000 bps
-            new anchor.BN(DAILY_CAP),
-            new anchor.BN(MIN_PAYOUT_LAMPORTS),
-            new anchor.BN(TOTAL_INVESTOR_ALLOCATION)
-          )
+          .initializeHonoraryPosition({
+            investorFeeShareBps: 10001, // Invalid: > 10000 bps
+            dailyCap: new anchor.BN(DAILY_CAP),
+            minPayoutLamports: new anchor.BN(MIN_PAYOUT_LAMPORTS),
+            totalInvestorAllocation: new anchor.BN(TOTAL_INVESTOR_ALLOCATION),
+            dustDestination: { creator: {} },
+            creatorVestingPeriod: new anchor.BN(0),
+            minPageSize: 1,
+            maxPageSize: 20,
+            merkleInvestorSet: false,
+            pullClaims: false,
+            payoutRecords: false,
+            payoutReceipts: false,
+            carryCappedExcess: false,
+            distributionOrder: { investorsFirst: {} },
+            roundingMode: { floor: {} },
+            feeShareSchedule: [],
+            dayAnchorOffset: null,
+            maxInvestors: 128,
+            deferCreatorPayouts: false,
+            minPayoutMode: { fixed: {} },
+            minPayoutBps: 0,
+            minCrankIntervalSeconds: 0,
+            creatorPaidPerPage: false,
+            streamSender: payer.publicKey,
+            residueDestination: { creator: {} },
+            distributionPeriod: new BN(86400),
+          })
           .accounts({
             payer: payer.publicKey,
             vault: vault.publicKey,
@@ -204,6 +249,7 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
             treasury: treasuryPda,
             position: mockPosition.publicKey,
             cpAmmProgram: new PublicKey("11111111111111111111111111111111"),
+            streamflowProgram: new PublicKey("11111111111111111111111111111111"),
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -213,7 +259,7 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
 
         expect.fail("Should have rejected invalid fee share");
       } catch (error) {
-        expect(error.message).to.include("InvalidTokenOrder");
+        expect(error.message).to.include("InvalidInvestorFeeShare");
       }
     });
   });
@@ -295,7 +341,7 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
       }
 
       const tx = await program.methods
-        .distributeFees(new anchor.BN(5)) // Process all 5 investors
+        .distributeFees(5, false, null, [], null, new anchor.BN(5)) // Process all 5 investors
         .accounts({
           payer: payer.publicKey,
           vault: vault.publicKey,
@@ -303,6 +349,8 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
           progress: progressPda,
           positionOwnerPda,
           position: Keypair.generate().publicKey, // Mock position
+          positionNftAccount: Keypair.generate().publicKey,
+          pool: mockPool.publicKey,
           treasury: treasuryPda,
           creatorQuoteAta,
           cpAmmProgram: new PublicKey("11111111111111111111111111111111"),
@@ -319,7 +367,7 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
       const progressAccount = await program.account.progressState.fetch(
         progressPda
       );
-      expect(progressAccount.dayComplete).to.equal(true);
+      expect(progressAccount.dayComplete).to.equal(1);
       console.log("Final progress state:", progressAccount);
     });
 
@@ -336,7 +384,7 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
 
       try {
         await program.methods
-          .distributeFees(new anchor.BN(5))
+          .distributeFees(5, false, null, [], null, new anchor.BN(5))
           .accounts({
             payer: payer.publicKey,
             vault: vault.publicKey,
@@ -344,6 +392,8 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
             progress: progressPda,
             positionOwnerPda,
             position: Keypair.generate().publicKey,
+            positionNftAccount: Keypair.generate().publicKey,
+            pool: mockPool.publicKey,
             treasury: treasuryPda,
             creatorQuoteAta,
             cpAmmProgram: new PublicKey("11111111111111111111111111111111"),
@@ -375,7 +425,7 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
 
       // First page (2 investors)
       const tx1 = await program.methods
-        .distributeFees(new anchor.BN(2))
+        .distributeFees(2, false, null, [], null, new anchor.BN(5))
         .accounts({
           payer: payer.publicKey,
           vault: vault.publicKey,
@@ -383,6 +433,8 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
           progress: progressPda,
           positionOwnerPda,
           position: Keypair.generate().publicKey,
+          positionNftAccount: Keypair.generate().publicKey,
+          pool: mockPool.publicKey,
           treasury: treasuryPda,
           creatorQuoteAta,
           cpAmmProgram: new PublicKey("11111111111111111111111111111111"),
@@ -400,11 +452,11 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
         progressPda
       );
       expect(progressAccount.paginationCursor.toNumber()).to.equal(2);
-      expect(progressAccount.dayComplete).to.equal(false);
+      expect(progressAccount.dayComplete).to.equal(0);
 
       // Second page (remaining 3 investors)
       const tx2 = await program.methods
-        .distributeFees(new anchor.BN(3))
+        .distributeFees(3, false, null, [], null, null)
         .accounts({
           payer: payer.publicKey,
           vault: vault.publicKey,
@@ -412,6 +464,8 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
           progress: progressPda,
           positionOwnerPda,
           position: Keypair.generate().publicKey,
+          positionNftAccount: Keypair.generate().publicKey,
+          pool: mockPool.publicKey,
           treasury: treasuryPda,
           creatorQuoteAta,
           cpAmmProgram: new PublicKey("11111111111111111111111111111111"),
@@ -426,7 +480,7 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
 
       // Verify day is complete
       progressAccount = await program.account.progressState.fetch(progressPda);
-      expect(progressAccount.dayComplete).to.equal(true);
+      expect(progressAccount.dayComplete).to.equal(1);
     });
   });
 
@@ -463,7 +517,7 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
 
       // Should complete successfully with 100% to creator
       const tx = await program.methods
-        .distributeFees(new anchor.BN(3))
+        .distributeFees(3, false, null, [], null, new anchor.BN(3))
         .accounts({
           payer: payer.publicKey,
           vault: vault.publicKey,
@@ -471,6 +525,8 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
           progress: progressPda,
           positionOwnerPda,
           position: Keypair.generate().publicKey,
+          positionNftAccount: Keypair.generate().publicKey,
+          pool: mockPool.publicKey,
           treasury: treasuryPda,
           creatorQuoteAta,
           cpAmmProgram: new PublicKey("11111111111111111111111111111111"),
@@ -487,7 +543,7 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
       const progressAccount = await program.account.progressState.fetch(
         progressPda
       );
-      expect(progressAccount.dayComplete).to.equal(true);
+      expect(progressAccount.dayComplete).to.equal(1);
     });
 
     it("Should handle dust amounts correctly", async () => {
@@ -530,7 +586,7 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
       ]);
 
       const tx = await program.methods
-        .distributeFees(new anchor.BN(3))
+        .distributeFees(3, false, null, [], null, new anchor.BN(3))
         .accounts({
           payer: payer.publicKey,
           vault: vault.publicKey,
@@ -538,6 +594,8 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
           progress: progressPda,
           positionOwnerPda,
           position: Keypair.generate().publicKey,
+          positionNftAccount: Keypair.generate().publicKey,
+          pool: mockPool.publicKey,
           treasury: treasuryPda,
           creatorQuoteAta,
           cpAmmProgram: new PublicKey("11111111111111111111111111111111"),
@@ -553,7 +611,7 @@ describe("DAMM v2 Honorary Fee Position - Core Tests", () => {
       const progressAccount = await program.account.progressState.fetch(
         progressPda
       );
-      expect(progressAccount.dayComplete).to.equal(true);
+      expect(progressAccount.dayComplete).to.equal(1);
     });
   });
 });